@@ -16,6 +16,7 @@
 // under the License.
 
 mod generate;
+mod sbom;
 
 use clap::{Parser, Subcommand};
 
@@ -37,6 +38,15 @@ enum Commands {
         #[arg(short, long)]
         path: String,
     },
+    /// Generate a dependency SBOM for a generated binding crate.
+    GenerateSbom {
+        /// Path to the binding crate (e.g. "services/s3")
+        #[arg(short, long)]
+        path: String,
+        /// Output format: "spdx" or "cyclonedx"
+        #[arg(short, long, default_value = "spdx")]
+        format: String,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
@@ -44,5 +54,6 @@ fn main() -> anyhow::Result<()> {
 
     match Cmd::parse().command {
         Commands::GenerateService { service, path } => generate::service::run(&service, &path),
+        Commands::GenerateSbom { path, format } => sbom::run(&path, &format),
     }
 }
\ No newline at end of file
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Benchmarks for `RetryLayer` construction and operator stacking.
+//!
+//! These track the cost of building the layer itself and the cost of
+//! layering it onto an `Operator`, which is the hot path when a fresh
+//! operator is built per request, as well as the cost of going through the
+//! `PyRetryLayer` pyclass wrapper defined in `src/factory.rs` itself.
+//!
+//! `factory` is pulled in by path rather than `use`d as a dependency: this
+//! package has no separate published name benches can reference, so this is
+//! the only way to exercise the actual `PyRetryLayer` wrapper rather than a
+//! stand-in.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use opendal::Operator;
+use opendal::services::Memory;
+use pyo3::prelude::*;
+use std::time::Duration;
+
+use ::pyo3_opendal as opyo3;
+
+#[path = "../src/factory.rs"]
+mod factory;
+
+fn minimal_retry_layer(c: &mut Criterion) {
+    c.bench_function("retry_layer_construct_minimal", |b| {
+        b.iter(|| black_box(opendal_layer_retry::RetryLayer::default()));
+    });
+}
+
+fn configured_retry_layer(c: &mut Criterion) {
+    c.bench_function("retry_layer_construct_configured", |b| {
+        b.iter(|| {
+            let retry = opendal_layer_retry::RetryLayer::default()
+                .with_max_times(5)
+                .with_factor(2.0)
+                .with_jitter()
+                .with_max_delay(Duration::from_secs(60))
+                .with_min_delay(Duration::from_secs(1));
+            black_box(retry)
+        });
+    });
+}
+
+fn operator_stacking(c: &mut Criterion) {
+    c.bench_function("retry_layer_operator_stack", |b| {
+        b.iter(|| {
+            let op = Operator::new(Memory::default()).unwrap().finish();
+            let retry = opendal_layer_retry::RetryLayer::default().with_max_times(3);
+            black_box(op.layer(retry))
+        });
+    });
+}
+
+/// Constructs `PyRetryLayer` with every option defaulted, the shape most
+/// callers hit.
+fn py_retry_layer_construct_default(c: &mut Criterion) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        c.bench_function("py_retry_layer_construct_default", |b| {
+            b.iter(|| {
+                black_box(
+                    factory::PyRetryLayer::new(py, None, None, false, None, None, None).unwrap(),
+                )
+            });
+        });
+    });
+}
+
+/// Constructs `PyRetryLayer` through its multi-arg `new()` path,
+/// with every option set, including a `notify` callback.
+fn py_retry_layer_construct_configured(c: &mut Criterion) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let notify = py.None();
+        c.bench_function("py_retry_layer_construct_configured", |b| {
+            b.iter(|| {
+                black_box(
+                    factory::PyRetryLayer::new(
+                        py,
+                        Some(5),
+                        Some(2.0),
+                        true,
+                        Some(60.0),
+                        Some(1.0),
+                        Some(notify.clone_ref(py)),
+                    )
+                    .unwrap(),
+                )
+            });
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    minimal_retry_layer,
+    configured_retry_layer,
+    operator_stacking,
+    py_retry_layer_construct_default,
+    py_retry_layer_construct_configured,
+);
+criterion_main!(benches);
@@ -118,6 +118,16 @@ impl PyRetryLayer {
     ///     Maximum delay (in seconds) between retries. Defaults to ``60.0``.
     /// min_delay : Optional[float]
     ///     Minimum delay (in seconds) between retries. Defaults to ``1.0``.
+    /// notify : Optional[Callable[[int, float, str], None]]
+    ///     Called on every retry with a retry count, the sleep duration (in
+    ///     seconds) that will be applied before the next attempt, and a
+    ///     string description of the error that triggered the retry. The
+    ///     count starts at ``1`` and runs across every operation this layer
+    ///     wraps; it does not restart at ``1`` for each new operation, so it
+    ///     is not a per-operation attempt number. This is a side-effect-only
+    ///     hook: retries proceed regardless of what it does, and an exception raised from
+    ///     it is printed rather than propagated, since it runs outside of
+    ///     any `Operator` call a caller could wrap in a `try`/`except`.
     ///
     /// Returns
     /// -------
@@ -129,14 +139,18 @@ impl PyRetryLayer {
         factor = None,
         jitter = false,
         max_delay = None,
-        min_delay = None
+        min_delay = None,
+        notify = None
     ))]
-    fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        py: Python,
         max_times: Option<usize>,
         factor: Option<f32>,
         jitter: bool,
         max_delay: Option<f64>,
         min_delay: Option<f64>,
+        notify: Option<Py<PyAny>>,
     ) -> PyResult<PyClassInitializer<Self>> {
         let mut retry = opendal_layer_retry::RetryLayer::default();
         if let Some(max_times) = max_times {
@@ -154,14 +168,38 @@ impl PyRetryLayer {
         if let Some(min_delay) = min_delay {
             retry = retry.with_min_delay(Duration::from_micros((min_delay * 1_000_000.0) as u64));
         }
+        if let Some(notify) = notify {
+            retry = retry.with_notify(retry_notify_callback(notify));
+        }
 
         let retry_layer = Self { l: retry };
-        let class = PyClassInitializer::from(opyo3::PyLayer::new()?).add_subclass(retry_layer);
+        let class = PyClassInitializer::from(opyo3::PyLayer::new(py)?).add_subclass(retry_layer);
 
         Ok(class)
     }
 }
 
+/// Builds the `opendal_layer_retry::RetryLayer::with_notify` closure for a
+/// user-supplied `notify` callback, marshalling each retry event across the
+/// GIL as `(attempt: int, sleep_secs: float, error: str)`.
+///
+/// Upstream's notify hook doesn't carry an attempt number, so one is tracked
+/// here via a shared counter captured by the closure.
+fn retry_notify_callback(
+    notify: Py<PyAny>,
+) -> impl Fn(&ocore::Error, Duration) + Send + Sync + 'static {
+    let attempt = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    move |err, dur| {
+        let attempt = attempt.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let error = err.to_string();
+        Python::with_gil(|py| {
+            if let Err(e) = notify.call1(py, (attempt, dur.as_secs_f64(), error)) {
+                e.print(py);
+            }
+        });
+    }
+}
+
 // ---
 
 // /// FS-specific helper functions
@@ -16,6 +16,7 @@
 // under the License.
 
 use pyo3::prelude::*;
+use pyo3::wrap_pyfunction;
 use pyo3_opendal::*;
 use pyo3_stub_gen::define_stub_info_gatherer;
 
@@ -36,7 +37,13 @@ fn _core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     add_pymodule!(py, m, "opendal", "capability", [PyCapability])?;
 
     // Layers module
-    add_pymodule!(py, m, "opendal", "layers", [PyLayer])?;
+    add_pymodule!(
+        py,
+        m,
+        "opendal",
+        "layers",
+        [PyLayer, PyLoggingLayer, PyDriver, PyTimeoutLayer]
+    )?;
 
     // Types module
     add_pymodule!(
@@ -44,9 +51,12 @@ fn _core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         m,
         "opendal",
         "types",
-        [Entry, EntryMode, Metadata, PresignedRequest]
+        [Entry, EntryMode, Metadata, export::PresignedRequest]
     )?;
 
+    // Extension point for out-of-tree services (see `pyo3_opendal::register_service`).
+    m.add_function(wrap_pyfunction!(register_service, m)?)?;
+
     m.add_class::<WriteOptions>()?;
     m.add_class::<ReadOptions>()?;
     m.add_class::<ListOptions>()?;
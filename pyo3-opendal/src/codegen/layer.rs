@@ -18,13 +18,88 @@
 use anyhow::{Result, anyhow};
 use cargo_metadata::MetadataCommand;
 use quote::{format_ident, quote};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use syn::{
-    Expr, ExprLit, ImplItem, Item, Lit, Meta, ReturnType, Type, TypePath, Visibility, parse_file,
+    Expr, ExprLit, Fields, GenericArgument, ImplItem, Item, Lit, Meta, PathArguments, ReturnType,
+    Type, TypePath, Visibility, parse_file,
 };
 
-pub fn generate(layer_name: &str, package_path: &Path) -> Result<String> {
+/// Maps a Rust primitive type name to its Python-facing type and doc label.
+/// Returns `None` for types that aren't directly representable as a simple
+/// scalar (e.g. enums, `Vec<T>`, `HashMap<K, V>`).
+fn primitive_type_info(type_str: &str) -> Option<(proc_macro2::TokenStream, &'static str)> {
+    match type_str {
+        "bool" => Some((quote!(bool), "bool")),
+        "String" => Some((quote!(String), "str")),
+        "usize" | "u64" | "i64" | "u32" | "u16" | "isize" | "i32" | "i16" | "i8" | "u8" => {
+            let t = format_ident!("{}", type_str);
+            Some((quote!(#t), "int"))
+        }
+        "f32" | "f64" => {
+            let t = format_ident!("{}", type_str);
+            Some((quote!(#t), "float"))
+        }
+        _ => None,
+    }
+}
+
+/// Options controlling how generated layer code references its surrounding
+/// crate, so the generator can be run against a module tree other than
+/// `pyo3-opendal` itself (e.g. a downstream crate that re-exports or renames
+/// `pyo3_opendal`).
+#[derive(Clone, Debug)]
+pub struct GenOptions {
+    /// Base path used to reach the `opyo3` (`pyo3_opendal`) re-export in the
+    /// generated `use` statement, e.g. `"crate"` (the default, for in-tree
+    /// generation) or `"my_crate"` for a downstream crate that re-exports it
+    /// under that name. Also used to derive the `pyo3` crate path passed to
+    /// `#[pyo3(crate = "...")]`, assumed reachable as `<crate_path>::pyo3`
+    /// unless `crate_path` is `"crate"`.
+    pub crate_path: String,
+    /// Renames applied to generated Python keyword arguments, keyed by
+    /// `"<layer_name>::<rust_arg_name>"` (e.g. `"retry::jitter"`). The value
+    /// replaces the Rust builder argument name everywhere it surfaces in
+    /// Python: the `#[pyo3(signature = ...)]`, the `new()` parameter, and the
+    /// docstring. Mirrors PyO3's own `#[pyo3(name = "...")]` at codegen time.
+    pub arg_overrides: HashMap<String, String>,
+    /// When set, attaches `#[pyclass(freelist = N)]` to the generated layer
+    /// pyclass so PyO3 recycles allocations across repeated construction and
+    /// teardown (e.g. building an operator stack per request). Off by default.
+    pub freelist: Option<u32>,
+}
+
+impl Default for GenOptions {
+    fn default() -> Self {
+        Self {
+            crate_path: "crate".to_string(),
+            arg_overrides: HashMap::new(),
+            freelist: None,
+        }
+    }
+}
+
+impl GenOptions {
+    fn pyo3_crate_path(&self) -> String {
+        if self.crate_path == "crate" {
+            "pyo3".to_string()
+        } else {
+            format!("{}::pyo3", self.crate_path)
+        }
+    }
+
+    /// Resolves the Python-facing name for a builder argument, applying the
+    /// `"<layer_name>::<rust_arg_name>"` override if one was registered.
+    fn py_arg_name(&self, layer_name: &str, rust_arg_name: &str) -> String {
+        self.arg_overrides
+            .get(&format!("{}::{}", layer_name, rust_arg_name))
+            .cloned()
+            .unwrap_or_else(|| rust_arg_name.to_string())
+    }
+}
+
+pub fn generate(layer_name: &str, package_path: &Path, options: &GenOptions) -> Result<String> {
     // 1. Find dependency path
     let dep_path = find_dependency_path(package_path, layer_name)?;
     let src_path = dep_path.join(format!("src/{}.rs", layer_name.replace('-', "_")));
@@ -64,62 +139,160 @@ pub fn generate(layer_name: &str, package_path: &Path) -> Result<String> {
 
     for method in methods {
         let method_name = format_ident!("{}", method.name);
-        let arg_name = format_ident!("{}", method.arg_name);
+        let py_arg_name = options.py_arg_name(layer_name, &method.arg_name);
+        let arg_name = format_ident!("{}", py_arg_name);
         let arg_type_str = method.arg_type.as_str();
 
-        // Type mapping and filtering. Only support types that map easily to Python.
-        let (py_type, default_val, is_bool, py_type_doc) = match arg_type_str {
-            "bool" => (quote!(bool), quote!(false), true, "bool"),
-            "String" => (quote!(String), quote!(None), false, "str"),
-            "usize" | "u64" | "i64" | "u32" | "u16" | "isize" | "i32" | "i16" | "i8" | "u8" => {
-                let t = format_ident!("{}", arg_type_str);
-                (quote!(#t), quote!(None), false, "int")
-            }
-            "f32" | "f64" => {
-                let t = format_ident!("{}", arg_type_str);
-                (quote!(#t), quote!(None), false, "float")
-            }
-            "Duration" => (quote!(std::time::Duration), quote!(None), false, "float"),
-            _ => {
-                // Skip unsupported types
+        if method.is_toggle {
+            doc_params.push(format!(
+                "{} : bool\n    {}",
+                py_arg_name,
+                method_doc_desc(&method)
+            ));
+            new_args.push(quote! { #arg_name: bool });
+            signature_args.push(quote! { #arg_name = false });
+            new_assignments.push(quote! {
+                if #arg_name {
+                    layer = layer.#method_name();
+                }
+            });
+            continue;
+        }
+
+        // Enums: the builder takes a C-like enum we discovered while walking the
+        // source AST. Expose it as a `str` and convert case-insensitively, raising
+        // `PyValueError` listing the accepted variants on a bad value.
+        if let Some(variants) = layer_def.enums.get(arg_type_str) {
+            let enum_ident = format_ident!("{}", arg_type_str);
+            let variant_idents: Vec<_> = variants.iter().map(|v| format_ident!("{}", v)).collect();
+            let variant_lowers: Vec<String> = variants.iter().map(|v| v.to_lowercase()).collect();
+            let valid_values = variants.join(", ");
+            let error_msg = format!(
+                "invalid value {{:?}} for `{}`, expected one of: {}",
+                py_arg_name, valid_values
+            );
+
+            doc_params.push(format!(
+                "{} : Optional[str]\n    {} One of: {}.",
+                py_arg_name,
+                method_doc_desc(&method),
+                valid_values
+            ));
+
+            new_args.push(quote! { #arg_name: Option<String> });
+            signature_args.push(quote! { #arg_name = None });
+            new_assignments.push(quote! {
+                if let Some(v) = #arg_name {
+                    let variant = match v.to_lowercase().as_str() {
+                        #(#variant_lowers => #layer_module::#enum_ident::#variant_idents,)*
+                        other => {
+                            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                                #error_msg,
+                                other
+                            )));
+                        }
+                    };
+                    layer = layer.#method_name(variant);
+                }
+            });
+            continue;
+        }
+
+        // `Vec<T>` of a primitive element type, e.g. `Vec<String>`.
+        if arg_type_str == "Vec" {
+            let Some(elem_type_str) = method.arg_generics.first() else {
                 eprintln!(
-                    "Skipping method {} due to unsupported type {}",
-                    method.name, arg_type_str
+                    "Skipping method {} due to unsupported type Vec<?>",
+                    method.name
                 );
                 continue;
-            }
-        };
+            };
+            let Some((elem_py_type, elem_doc)) = primitive_type_info(elem_type_str) else {
+                eprintln!(
+                    "Skipping method {} due to unsupported element type {}",
+                    method.name, elem_type_str
+                );
+                continue;
+            };
 
-        // Generate docstring entry
-        let doc_desc = if method.docs.is_empty() {
-            format!("See `{}`.", method.name)
-        } else {
-            // Join lines, trimming and adding indentation.
-            // If a line starts with `#`, treat it as a header and ensure it starts on a new line.
-            let mut lines = Vec::new();
-            for s in method.docs.iter() {
-                let trimmed = s.trim();
-                if !trimmed.is_empty() {
-                    lines.push(trimmed.to_string());
+            doc_params.push(format!(
+                "{} : Optional[List[{}]]\n    {}",
+                py_arg_name,
+                elem_doc,
+                method_doc_desc(&method)
+            ));
+
+            new_args.push(quote! { #arg_name: Option<Vec<#elem_py_type>> });
+            signature_args.push(quote! { #arg_name = None });
+            new_assignments.push(quote! {
+                if let Some(vs) = #arg_name {
+                    let mut values = Vec::with_capacity(vs.len());
+                    for v in vs {
+                        values.push(v);
+                    }
+                    layer = layer.#method_name(values);
                 }
+            });
+            continue;
+        }
+
+        // `HashMap<String, String>`.
+        if arg_type_str == "HashMap" {
+            let is_string_map = method.arg_generics.len() == 2
+                && method.arg_generics[0] == "String"
+                && method.arg_generics[1] == "String";
+            if !is_string_map {
+                eprintln!(
+                    "Skipping method {} due to unsupported map type {:?}",
+                    method.name, method.arg_generics
+                );
+                continue;
             }
-            lines.join("\n    ")
+
+            doc_params.push(format!(
+                "{} : Optional[Dict[str, str]]\n    {}",
+                py_arg_name,
+                method_doc_desc(&method)
+            ));
+
+            new_args.push(quote! { #arg_name: Option<std::collections::HashMap<String, String>> });
+            signature_args.push(quote! { #arg_name = None });
+            new_assignments.push(quote! {
+                if let Some(v) = #arg_name {
+                    layer = layer.#method_name(v);
+                }
+            });
+            continue;
+        }
+
+        // Type mapping and filtering. Only support types that map easily to Python.
+        let (py_type, default_val, is_bool, py_type_doc) = match arg_type_str {
+            "Duration" => (quote!(std::time::Duration), quote!(None), false, "float"),
+            _ => match primitive_type_info(arg_type_str) {
+                Some((py_type, py_type_doc)) => {
+                    let is_bool = arg_type_str == "bool";
+                    let default_val = if is_bool { quote!(false) } else { quote!(None) };
+                    (py_type, default_val, is_bool, py_type_doc)
+                }
+                None => {
+                    // Skip unsupported types
+                    eprintln!(
+                        "Skipping method {} due to unsupported type {}",
+                        method.name, arg_type_str
+                    );
+                    continue;
+                }
+            },
         };
 
         doc_params.push(format!(
             "{} : Optional[{}]\n    {}",
-            method.arg_name, py_type_doc, doc_desc
+            py_arg_name,
+            py_type_doc,
+            method_doc_desc(&method)
         ));
 
-        if method.is_toggle {
-            new_args.push(quote! { #arg_name: bool });
-            signature_args.push(quote! { #arg_name = #default_val });
-            new_assignments.push(quote! {
-                if #arg_name {
-                    layer = layer.#method_name();
-                }
-            });
-        } else if is_bool {
+        if is_bool {
             new_args.push(quote! { #arg_name: bool });
             signature_args.push(quote! { #arg_name = #default_val });
             new_assignments.push(quote! {
@@ -156,17 +329,31 @@ pub fn generate(layer_name: &str, package_path: &Path) -> Result<String> {
 
     let doc_attributes = doc_string.lines().map(|line| quote!(#[doc = #line]));
 
+    let crate_path: proc_macro2::TokenStream = options.crate_path.parse().map_err(|e| {
+        anyhow!(
+            "invalid crate_path {:?} in GenOptions: {}",
+            options.crate_path,
+            e
+        )
+    })?;
+    let pyo3_crate_path = options.pyo3_crate_path();
+    let freelist_attr = options
+        .freelist
+        .map(|n| quote! { , freelist = #n })
+        .unwrap_or_default();
+
     let code = quote! {
         //! This file is automatically generated by `pyo3_opendal::codegen::generate_layer_stub`
         #![allow(clippy::possible_missing_else)]
 
         use #layer_module::#layer_struct_ident;
-        use crate::opyo3;
+        use #crate_path::opyo3;
         use pyo3::prelude::*;
         use pyo3_stub_gen::derive::*;
 
         #[gen_stub_pyclass]
-        #[pyclass(name = #layer_pascal_lit, extends=opyo3::PyLayer)]
+        #[pyclass(module = "opendal.layers", name = #layer_pascal_lit, extends=opyo3::PyLayer #freelist_attr)]
+        #[pyo3(crate = #pyo3_crate_path)]
         #[derive(Clone)]
         pub struct #py_layer_ident(#layer_struct_ident);
 
@@ -178,17 +365,18 @@ pub fn generate(layer_name: &str, package_path: &Path) -> Result<String> {
 
         #[gen_stub_pymethods]
         #[pymethods]
+        #[pyo3(crate = #pyo3_crate_path)]
         impl #py_layer_ident {
             #(#doc_attributes)*
             #[gen_stub(override_return_type(type_repr = "opendal.layers.Layer", imports=("opendal")))]
             #[new]
             #[pyo3(signature = (#(#signature_args),*))]
             #[allow(unused)]
-            fn new(#(#new_args),*) -> PyResult<PyClassInitializer<Self>> {
+            fn new(py: Python, #(#new_args),*) -> PyResult<PyClassInitializer<Self>> {
                 let mut layer = #layer_struct_ident::default();
                 #(#new_assignments)*
 
-                let class = PyClassInitializer::from(opyo3::PyLayer::new()?).add_subclass(Self(layer));
+                let class = PyClassInitializer::from(opyo3::PyLayer::new(py)?).add_subclass(Self(layer));
                 Ok(class)
             }
         }
@@ -202,13 +390,36 @@ struct MethodDef {
     name: String,
     arg_name: String,
     arg_type: String,
+    /// Inner type names for a generic argument, e.g. `["String"]` for
+    /// `Vec<String>` or `["String", "String"]` for `HashMap<String, String>`.
+    arg_generics: Vec<String>,
     docs: Vec<String>,
     is_toggle: bool,
 }
 
+/// Joins a method's doc comment lines into the paragraph used in the generated
+/// docstring, falling back to a generic reference when the method is undocumented.
+fn method_doc_desc(method: &MethodDef) -> String {
+    if method.docs.is_empty() {
+        return format!("See `{}`.", method.name);
+    }
+
+    let mut lines = Vec::new();
+    for s in method.docs.iter() {
+        let trimmed = s.trim();
+        if !trimmed.is_empty() {
+            lines.push(trimmed.to_string());
+        }
+    }
+    lines.join("\n    ")
+}
+
 #[derive(Default, Clone, Debug)]
 struct LayerDef {
     methods: Vec<MethodDef>,
+    /// C-like enums discovered in the layer's source file, keyed by enum name
+    /// with their unit variant names in declaration order.
+    enums: HashMap<String, Vec<String>>,
 }
 
 fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
@@ -219,6 +430,26 @@ fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
     let struct_name = format!("{}Layer", layer_pascal);
 
     let mut methods = Vec::new();
+    let mut enums = HashMap::new();
+
+    for item in &ast.items {
+        // Record C-like enums (fieldless variants only) so builder args typed
+        // with them can be exposed to Python as a validated string.
+        if let Item::Enum(item_enum) = item {
+            let is_c_like = item_enum
+                .variants
+                .iter()
+                .all(|v| matches!(v.fields, Fields::Unit));
+            if is_c_like {
+                let variants = item_enum
+                    .variants
+                    .iter()
+                    .map(|v| v.ident.to_string())
+                    .collect::<Vec<_>>();
+                enums.insert(item_enum.ident.to_string(), variants);
+            }
+        }
+    }
 
     for item in ast.items {
         if let Item::Impl(impl_block) = item {
@@ -282,15 +513,9 @@ fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
                                             "arg".to_string()
                                         };
 
-                                        let arg_type = if let Type::Path(tp) = &*arg.ty {
-                                            if let Some(seg) = tp.path.segments.last() {
-                                                seg.ident.to_string()
-                                            } else {
-                                                "unknown".to_string()
-                                            }
-                                        } else {
-                                            "unknown".to_string()
-                                        };
+                                        let (arg_type, arg_generics) = type_name_and_generics(
+                                            &arg.ty,
+                                        );
 
                                         // Extract docs
                                         let docs: Vec<String> = method
@@ -315,6 +540,7 @@ fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
                                             name: sig.ident.to_string(),
                                             arg_name,
                                             arg_type,
+                                            arg_generics,
                                             docs,
                                             is_toggle: false,
                                         });
@@ -350,6 +576,7 @@ fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
                                                 name,
                                                 arg_name,
                                                 arg_type: "bool".to_string(),
+                                                arg_generics: Vec::new(),
                                                 docs,
                                                 is_toggle: true,
                                             });
@@ -364,7 +591,36 @@ fn parse_layer_def(path: &Path, layer_name: &str) -> Result<LayerDef> {
         }
     }
 
-    Ok(LayerDef { methods })
+    Ok(LayerDef { methods, enums })
+}
+
+/// Resolves a builder argument's type to its last path segment name plus, for
+/// a single level of generics (`Vec<T>`, `HashMap<K, V>`), the names of its
+/// type arguments.
+fn type_name_and_generics(ty: &Type) -> (String, Vec<String>) {
+    let Type::Path(tp) = ty else {
+        return ("unknown".to_string(), Vec::new());
+    };
+    let Some(seg) = tp.path.segments.last() else {
+        return ("unknown".to_string(), Vec::new());
+    };
+
+    let name = seg.ident.to_string();
+    let generics = match &seg.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(Type::Path(inner)) => {
+                    inner.path.segments.last().map(|s| s.ident.to_string())
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    (name, generics)
 }
 
 fn find_dependency_path(package_path: &Path, layer_name: &str) -> Result<PathBuf> {
@@ -0,0 +1,388 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::codegen::types::get_type_info_from_str;
+use crate::codegen::utils::{find_dependency_path, to_pascal};
+use anyhow::{Result, anyhow};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use syn::{
+    Expr, ExprLit, Fields, GenericArgument, Item, Lit, Meta, PathArguments, Type, Visibility,
+    parse_file,
+};
+
+/// Introspects a service's config struct (e.g. `S3Config`) and generates a
+/// typed `create_<service>_operator`/`create_<service>_async_operator` pair,
+/// replacing the stringly-typed `**kwargs` factories that used to be
+/// hand-written per service.
+pub fn generate_service_stub(service_name: &str, package_path: &Path) -> Result<String> {
+    // 1. Find dependency path
+    let dep_name = format!("opendal-service-{}", service_name);
+    let dep_path = find_dependency_path(package_path, &dep_name)?;
+    let config_path = dep_path.join("src/config.rs");
+
+    if !config_path.exists() {
+        return Err(anyhow!("Config file not found at {:?}", config_path));
+    }
+
+    println!("cargo:rerun-if-changed={}", config_path.display());
+
+    // 2. Parse the service's config struct
+    let service_pascal = to_pascal(service_name);
+    let service_snake = service_name.replace('-', "_");
+    let struct_name = format!("{}Config", service_pascal);
+    let config_def = parse_config_def(&config_path, &struct_name)?;
+
+    // 3. Generate typed keyword arguments for the factory functions
+    let mut new_args = Vec::new();
+    // Every generated factory is keyword-only: field order isn't something
+    // callers should depend on, and keyword-only args impose no relative
+    // ordering between defaulted and non-defaulted parameters the way
+    // positional ones would.
+    let mut signature_args = vec![quote!(*)];
+    let mut assemble_stmts = Vec::new();
+    let mut doc_params = Vec::new();
+    let mut literal_type_defs = Vec::new();
+
+    for field in &config_def.fields {
+        let arg_name = format_ident!("{}", field.name);
+        let field_name_lit = field.name.clone();
+
+        let doc_desc = if field.docs.is_empty() {
+            format!("See `{}`.", field.name)
+        } else {
+            field
+                .docs
+                .iter()
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n    ")
+        };
+
+        // Enum-like config options (e.g. a `ChecksumAlgorithm`) are exposed as
+        // a generated type whose `PyStubType` impl renders as `Literal[...]`
+        // of the real variants, so the `.pyi` annotation itself -- not just
+        // the docstring -- is narrowed enough for a type-checker to catch a
+        // bad value at author time.
+        if let Some(variants) = config_def.enums.get(&field.type_str) {
+            let literal_values = variants
+                .iter()
+                .map(|v| format!("\"{}\"", v.to_lowercase()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let valid_values = variants.join(", ");
+            let error_msg = format!(
+                "invalid value {{:?}} for `{}`, expected one of: {}",
+                field.name, valid_values
+            );
+            let variant_idents: Vec<_> = variants.iter().map(|v| format_ident!("{}", v)).collect();
+            let variant_lowers: Vec<String> = variants.iter().map(|v| v.to_lowercase()).collect();
+            let literal_type_ident =
+                format_ident!("{}{}Literal", service_pascal, to_pascal(&field.name));
+            let literal_repr = format!("Literal[{}]", literal_values);
+
+            doc_params.push(format!(
+                "{} : Optional[Literal[{}]]\n    {}",
+                field.name, literal_values, doc_desc
+            ));
+            new_args.push(quote! { #arg_name: Option<#literal_type_ident> });
+            signature_args.push(quote! { #arg_name = None });
+            assemble_stmts.push(quote! {
+                if let Some(v) = #arg_name {
+                    map.insert(#field_name_lit.to_string(), v.to_string());
+                }
+            });
+            literal_type_defs.push(quote! {
+                #[derive(Clone, Copy)]
+                enum #literal_type_ident {
+                    #(#variant_idents),*
+                }
+
+                impl #literal_type_ident {
+                    fn as_str(self) -> &'static str {
+                        match self {
+                            #(Self::#variant_idents => stringify!(#variant_idents)),*
+                        }
+                    }
+                }
+
+                impl<'py> pyo3::FromPyObject<'py> for #literal_type_ident {
+                    fn extract_bound(ob: &pyo3::Bound<'py, pyo3::PyAny>) -> pyo3::PyResult<Self> {
+                        let s: String = ob.extract()?;
+                        match s.to_lowercase().as_str() {
+                            #(#variant_lowers => Ok(Self::#variant_idents),)*
+                            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                                #error_msg,
+                                other
+                            ))),
+                        }
+                    }
+                }
+
+                impl std::fmt::Display for #literal_type_ident {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        f.write_str(self.as_str())
+                    }
+                }
+
+                impl pyo3_stub_gen::PyStubType for #literal_type_ident {
+                    fn type_output() -> pyo3_stub_gen::TypeInfo {
+                        pyo3_stub_gen::TypeInfo::unqualified(#literal_repr)
+                    }
+                    fn type_input() -> pyo3_stub_gen::TypeInfo {
+                        pyo3_stub_gen::TypeInfo::unqualified(#literal_repr)
+                    }
+                }
+            });
+            continue;
+        }
+
+        let type_info = get_type_info_from_str(&field.type_str);
+        if type_info.py_type_doc.is_empty() {
+            eprintln!(
+                "Skipping field {} due to unsupported type {}",
+                field.name, field.type_str
+            );
+            continue;
+        }
+
+        let py_type = &type_info.rust_type;
+        let py_type_doc = &type_info.py_type_doc;
+
+        if field.is_option {
+            doc_params.push(format!(
+                "{} : Optional[{}]\n    {}",
+                field.name, py_type_doc, doc_desc
+            ));
+            new_args.push(quote! { #arg_name: Option<#py_type> });
+            signature_args.push(quote! { #arg_name = None });
+            assemble_stmts.push(quote! {
+                if let Some(v) = #arg_name {
+                    map.insert(#field_name_lit.to_string(), v.to_string());
+                }
+            });
+        } else {
+            doc_params.push(format!("{} : {}\n    {}", field.name, py_type_doc, doc_desc));
+            new_args.push(quote! { #arg_name: #py_type });
+            signature_args.push(quote! { #arg_name });
+            assemble_stmts.push(quote! {
+                map.insert(#field_name_lit.to_string(), #arg_name.to_string());
+            });
+        }
+    }
+
+    let doc_string = if doc_params.is_empty() {
+        format!("Create a new {} operator.", service_pascal)
+    } else {
+        format!(
+            "Create a new {} operator.\n\nParameters\n----------\n{}",
+            service_pascal,
+            doc_params.join("\n")
+        )
+    };
+    let doc_attributes: Vec<_> = doc_string
+        .lines()
+        .map(|line| quote!(#[doc = #line]))
+        .collect();
+
+    let service_crate = format_ident!("opendal_service_{}", service_snake);
+    let scheme_const = format_ident!("{}_SCHEME", service_name.to_uppercase());
+    let create_op_fn = format_ident!("create_{}_operator", service_snake);
+    let create_async_fn = format_ident!("create_{}_async_operator", service_snake);
+
+    let code = quote! {
+        //! This file is automatically generated by `pyo3_opendal::codegen::service::generate_service_stub`
+
+        use #service_crate::#scheme_const;
+        use pyo3::prelude::*;
+        use pyo3_opendal::export::{OpendalAsyncOperator, OpendalOperator};
+        use pyo3_opendal::layers::PyRuntimeLayer;
+        use pyo3_opendal::ocore::Operator;
+        use pyo3_stub_gen::PyStubType;
+        use pyo3_stub_gen::derive::*;
+        use std::collections::HashMap;
+
+        #(#literal_type_defs)*
+
+        #(#doc_attributes)*
+        #[gen_stub_pyfunction]
+        #[pyfunction]
+        #[pyo3(signature = (#(#signature_args),*))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn #create_op_fn(py: Python, #(#new_args),*) -> PyResult<OpendalOperator> {
+            let mut map: HashMap<String, String> = HashMap::new();
+            #(#assemble_stmts)*
+
+            let runtime = pyo3_async_runtimes::tokio::get_runtime();
+            let handle = pyo3_opendal::shared_runtime_handle(py)?;
+
+            let op = Operator::via_iter(#scheme_const, map.clone())
+                .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("build error: {err}")))?
+                .layer(PyRuntimeLayer::new(handle));
+
+            let _guard = runtime.enter();
+            let op = pyo3_opendal::ocore::blocking::Operator::new(op).map_err(|err| {
+                pyo3::exceptions::PyValueError::new_err(format!("blocking build error: {err}"))
+            })?;
+
+            Ok(OpendalOperator::new(op, map))
+        }
+
+        #(#doc_attributes)*
+        #[gen_stub_pyfunction]
+        #[pyfunction]
+        #[pyo3(signature = (#(#signature_args),*))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn #create_async_fn(py: Python, #(#new_args),*) -> PyResult<OpendalAsyncOperator> {
+            let mut map: HashMap<String, String> = HashMap::new();
+            #(#assemble_stmts)*
+
+            let handle = pyo3_opendal::shared_runtime_handle(py)?;
+
+            let op = Operator::via_iter(#scheme_const, map.clone())
+                .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("build error: {err}")))?
+                .layer(PyRuntimeLayer::new(handle));
+
+            Ok(OpendalAsyncOperator::new(op, map))
+        }
+    };
+
+    Ok(code.to_string())
+}
+
+struct ConfigFieldDef {
+    name: String,
+    /// Last path segment of the field's type, with `Option<T>` unwrapped to `T`.
+    type_str: String,
+    is_option: bool,
+    docs: Vec<String>,
+}
+
+/// A service's config struct fields, plus every C-like (fieldless) enum
+/// declared alongside it, keyed by enum name, so enum-typed fields can be
+/// validated and annotated as `Literal[...]` instead of a bare `str`.
+struct ConfigDef {
+    fields: Vec<ConfigFieldDef>,
+    enums: HashMap<String, Vec<String>>,
+}
+
+fn parse_config_def(path: &Path, struct_name: &str) -> Result<ConfigDef> {
+    let content = fs::read_to_string(path)?;
+    let ast = parse_file(&content)?;
+
+    let mut enums = HashMap::new();
+    for item in &ast.items {
+        let Item::Enum(item_enum) = item else {
+            continue;
+        };
+        let is_c_like = item_enum
+            .variants
+            .iter()
+            .all(|v| matches!(v.fields, Fields::Unit));
+        if !is_c_like {
+            continue;
+        }
+        let variants = item_enum
+            .variants
+            .iter()
+            .map(|v| v.ident.to_string())
+            .collect();
+        enums.insert(item_enum.ident.to_string(), variants);
+    }
+
+    for item in ast.items {
+        let Item::Struct(item_struct) = item else {
+            continue;
+        };
+        if item_struct.ident != struct_name {
+            continue;
+        }
+
+        let Fields::Named(named) = item_struct.fields else {
+            return Ok(ConfigDef {
+                fields: Vec::new(),
+                enums,
+            });
+        };
+
+        let mut fields = Vec::new();
+        for field in named.named {
+            if !matches!(field.vis, Visibility::Public(_)) {
+                continue;
+            }
+            let Some(ident) = field.ident else {
+                continue;
+            };
+
+            let (is_option, type_str) = option_inner(&field.ty);
+
+            let docs: Vec<String> = field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .filter_map(|attr| {
+                    if let Meta::NameValue(meta) = &attr.meta {
+                        if let Expr::Lit(ExprLit {
+                            lit: Lit::Str(s), ..
+                        }) = &meta.value
+                        {
+                            return Some(s.value().trim().to_string());
+                        }
+                    }
+                    None
+                })
+                .collect();
+
+            fields.push(ConfigFieldDef {
+                name: ident.to_string(),
+                type_str,
+                is_option,
+                docs,
+            });
+        }
+        return Ok(ConfigDef { fields, enums });
+    }
+
+    Err(anyhow!("Config struct {} not found in {:?}", struct_name, path))
+}
+
+/// Resolves a field's type to `(is_option, inner_type_name)`, unwrapping a
+/// single layer of `Option<T>` if present.
+fn option_inner(ty: &Type) -> (bool, String) {
+    let Type::Path(tp) = ty else {
+        return (false, "unknown".to_string());
+    };
+    let Some(seg) = tp.path.segments.last() else {
+        return (false, "unknown".to_string());
+    };
+
+    if seg.ident == "Option" {
+        if let PathArguments::AngleBracketed(args) = &seg.arguments {
+            if let Some(GenericArgument::Type(Type::Path(inner))) = args.args.first() {
+                if let Some(inner_seg) = inner.path.segments.last() {
+                    return (true, inner_seg.ident.to_string());
+                }
+            }
+        }
+        return (true, "unknown".to_string());
+    }
+
+    (false, seg.ident.to_string())
+}
@@ -58,7 +58,13 @@ pub fn get_type_info_from_str(type_str: &str) -> TypeInfo {
             }
         }
         "Duration" => TypeInfo {
-            rust_type: quote!(std::time::Duration),
+            // Accepts a `datetime.timedelta`, a bare number of seconds, or a
+            // duration string, and renders to the string services expect
+            // (e.g. "10s") via `ConfigDuration`'s `Display` impl -- see
+            // `pyo3_opendal::ConfigDuration`. `std::time::Duration` itself
+            // has no `Display`/`ToString` impl, which is why this can't map
+            // there the way the primitive arms above do.
+            rust_type: quote!(pyo3_opendal::ConfigDuration),
             py_type_doc: "datetime.timedelta".to_string(),
             default_val: quote!(None),
             is_bool: false,
@@ -87,10 +93,12 @@ pub fn get_type_info_from_config_type(config_type: ConfigType) -> TypeInfo {
             is_bool: false,
         },
         ConfigType::Duration => TypeInfo {
-            // TODO: In the future, we should support converting from datetime.timedelta to String
-            // For now, services expect String for duration
-            rust_type: quote!(String),
-            py_type_doc: "str".to_string(),
+            // Accepts a `datetime.timedelta`, a bare number of seconds, or a
+            // duration string, and renders to the string services expect
+            // (e.g. "10s") via `ConfigDuration`'s `Display` impl -- see
+            // `pyo3_opendal::ConfigDuration`.
+            rust_type: quote!(pyo3_opendal::ConfigDuration),
+            py_type_doc: "datetime.timedelta".to_string(),
             default_val: quote!(None),
             is_bool: false,
         },
@@ -25,6 +25,8 @@ use pyo3::prelude::*;
 
 use pyo3::IntoPyObjectExt;
 
+use pyo3_stub_gen::derive::*;
+
 use std::collections::HashMap;
 
 /// A wrapper around [`ocore::blocking::Operator`] that implements [`IntoPyObject`] to convert to a
@@ -38,6 +40,88 @@ impl OpendalOperator {
     pub fn new(op: ocore::blocking::Operator, map: HashMap<String, String>) -> Self {
         Self { op, map }
     }
+
+    /// Reads `path`, optionally a specific `version` rather than the current
+    /// one (only meaningful when the backing service has versioning enabled,
+    /// e.g. `S3Service.enable_versioning`).
+    pub fn read_version(&self, path: &str, version: Option<&str>) -> ocore::Result<Vec<u8>> {
+        let mut req = self.op.read_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        Ok(req.call()?.to_vec())
+    }
+
+    /// Stats `path`, optionally a specific `version`.
+    pub fn stat_version(&self, path: &str, version: Option<&str>) -> ocore::Result<ocore::Metadata> {
+        let mut req = self.op.stat_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        req.call()
+    }
+
+    /// Deletes `path`, optionally a specific `version` rather than inserting
+    /// a delete marker over the current one.
+    pub fn delete_version(&self, path: &str, version: Option<&str>) -> ocore::Result<()> {
+        let mut req = self.op.delete_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        req.call()
+    }
+
+    /// Lists every version of every object under `path`, annotated with its
+    /// version id and whether it's the current version of that path.
+    ///
+    /// Services that support versioning (S3, R2, OSS) return versions of a
+    /// given key most-recent-first, so the first time a path is seen here is
+    /// its current version.
+    pub fn list_with_versions(&self, path: &str) -> ocore::Result<Vec<VersionedEntry>> {
+        let entries = self.op.list_with(path).versions(true).call()?;
+        let mut seen = std::collections::HashSet::new();
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let path = entry.path().to_string();
+                let is_latest = seen.insert(path.clone());
+                VersionedEntry {
+                    version: entry.metadata().version().map(str::to_string),
+                    path,
+                    is_latest,
+                }
+            })
+            .collect())
+    }
+
+    /// Returns a presigned `GET` request for `path`, valid for `duration`,
+    /// so any already-built operator can presign without going through a
+    /// service-specific config (e.g. `S3Service.presign_read`).
+    pub fn presign_read(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_read(path, duration)?.into())
+    }
+
+    /// Returns a presigned `PUT` request for `path`, valid for `duration`.
+    pub fn presign_write(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_write(path, duration)?.into())
+    }
+
+    /// Returns a presigned `HEAD` request for `path`, valid for `duration`.
+    pub fn presign_stat(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_stat(path, duration)?.into())
+    }
 }
 
 impl<'py> IntoPyObject<'py> for OpendalOperator {
@@ -66,6 +150,82 @@ impl OpendalAsyncOperator {
     pub fn new(op: ocore::Operator, map: HashMap<String, String>) -> Self {
         Self { op, map }
     }
+
+    /// Async counterpart of [`OpendalOperator::read_version`].
+    pub async fn read_version(&self, path: &str, version: Option<&str>) -> ocore::Result<Vec<u8>> {
+        let mut req = self.op.read_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        Ok(req.await?.to_vec())
+    }
+
+    /// Async counterpart of [`OpendalOperator::stat_version`].
+    pub async fn stat_version(
+        &self,
+        path: &str,
+        version: Option<&str>,
+    ) -> ocore::Result<ocore::Metadata> {
+        let mut req = self.op.stat_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        req.await
+    }
+
+    /// Async counterpart of [`OpendalOperator::delete_version`].
+    pub async fn delete_version(&self, path: &str, version: Option<&str>) -> ocore::Result<()> {
+        let mut req = self.op.delete_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        req.await
+    }
+
+    /// Async counterpart of [`OpendalOperator::list_with_versions`].
+    pub async fn list_with_versions(&self, path: &str) -> ocore::Result<Vec<VersionedEntry>> {
+        let entries = self.op.list_with(path).versions(true).await?;
+        let mut seen = std::collections::HashSet::new();
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let path = entry.path().to_string();
+                let is_latest = seen.insert(path.clone());
+                VersionedEntry {
+                    version: entry.metadata().version().map(str::to_string),
+                    path,
+                    is_latest,
+                }
+            })
+            .collect())
+    }
+
+    /// Async counterpart of [`OpendalOperator::presign_read`].
+    pub async fn presign_read(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_read(path, duration).await?.into())
+    }
+
+    /// Async counterpart of [`OpendalOperator::presign_write`].
+    pub async fn presign_write(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_write(path, duration).await?.into())
+    }
+
+    /// Async counterpart of [`OpendalOperator::presign_stat`].
+    pub async fn presign_stat(
+        &self,
+        path: &str,
+        duration: std::time::Duration,
+    ) -> ocore::Result<PresignedRequest> {
+        Ok(self.op.presign_stat(path, duration).await?.into())
+    }
 }
 
 impl<'py> IntoPyObject<'py> for OpendalAsyncOperator {
@@ -82,3 +242,56 @@ impl<'py> IntoPyObject<'py> for OpendalAsyncOperator {
             .call_method1(intern!(py, "_from_capsule"), (capsule, map))
     }
 }
+
+/// A presigned HTTP request returned by a service's `presign_read`/
+/// `presign_write`/`presign_stat` methods (see e.g. `S3Service`): everything a
+/// caller needs to issue the request itself (from a browser, curl, etc.)
+/// without going through an `Operator` at all.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "PresignedRequest", get_all, frozen)]
+pub struct PresignedRequest {
+    /// HTTP method to use, e.g. `"GET"` or `"PUT"`.
+    pub method: String,
+    /// Fully-qualified, signed URL.
+    pub url: String,
+    /// Headers that must be sent along with the request for the signature to validate.
+    pub headers: HashMap<String, String>,
+}
+
+impl From<ocore::raw::PresignedRequest> for PresignedRequest {
+    fn from(req: ocore::raw::PresignedRequest) -> Self {
+        let headers = req
+            .header()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or_default().to_string()))
+            .collect();
+
+        Self {
+            method: req.method().to_string(),
+            url: req.uri().to_string(),
+            headers,
+        }
+    }
+}
+
+/// A single listed object version, as returned by `list_with_versions`.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "VersionedEntry", get_all, frozen)]
+pub struct VersionedEntry {
+    /// Path of the object this version belongs to.
+    pub path: String,
+    /// Version id, if the backing service assigns one to this entry.
+    pub version: Option<String>,
+    /// Whether this is the current (most recently written) version of `path`.
+    pub is_latest: bool,
+}
+
+/// Compile-time audit: both wrappers must stay `Send + Sync` so a free-threaded
+/// (no-GIL, PEP 703) interpreter can hand them to multiple threads at once.
+/// [`ocore::Operator`]/[`ocore::blocking::Operator`] already guarantee this
+/// upstream; this just keeps it true as fields are added here.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<OpendalOperator>();
+    assert_send_sync::<OpendalAsyncOperator>();
+};
@@ -0,0 +1,422 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A [`FallbackOperator`](PyFallbackOperator) chains several existing
+//! operators (e.g. a local `fs` cache, a read-through S3 mirror, and a slow
+//! origin) and resolves reads against them in priority order, the same way a
+//! fallback-source chain resolves a resource by asking each source in turn
+//! until one has it.
+//!
+//! Member operators are imported from their `opendal.Operator` PyCapsule, the
+//! same FFI handoff [`crate::ffi`] already uses to move operators between
+//! independently compiled extension modules.
+
+use std::collections::BTreeMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+use pyo3_stub_gen::derive::*;
+
+use crate::ffi::from_operator_capsule;
+use crate::ocore;
+use crate::utils::Buffer;
+
+/// Returns `true` if an error on one source should fall through to the next
+/// source in the chain, rather than propagate immediately.
+///
+/// `NotFound` always falls through. Any other error is delegated to
+/// `predicate` (called with the error message and whether OpenDAL considers
+/// the error temporary); with no predicate, a temporary error falls through
+/// and a permanent one propagates, mirroring `RetryLayer`'s notion of what
+/// is worth retrying.
+fn should_fallback(
+    py: Python<'_>,
+    err: &ocore::Error,
+    predicate: Option<&Py<PyAny>>,
+) -> PyResult<bool> {
+    if err.kind() == ocore::ErrorKind::NotFound {
+        return Ok(true);
+    }
+
+    match predicate {
+        Some(predicate) => predicate
+            .bind(py)
+            .call1((err.to_string(), err.is_temporary()))?
+            .extract::<bool>(),
+        None => Ok(err.is_temporary()),
+    }
+}
+
+fn capsules_to_operators(operators: Vec<Bound<PyCapsule>>) -> PyResult<Vec<ocore::Operator>> {
+    if operators.is_empty() {
+        return Err(PyValueError::new_err(
+            "FallbackOperator requires at least one operator",
+        ));
+    }
+    operators.iter().map(from_operator_capsule).collect()
+}
+
+/// Chains multiple operators and resolves `read`/`stat`/`list` by querying
+/// each in priority order, returning the first source that has the path.
+///
+/// Parameters
+/// ----------
+/// operators : list[capsule]
+///     The member operators, highest priority first, each passed as the
+///     PyCapsule backing an existing `opendal.Operator`.
+/// write_all : bool
+///     When `False` (the default), `write`/`delete` only target the primary
+///     (first) operator. When `True`, the operation fans out to every member
+///     operator.
+/// fallback_predicate : Optional[Callable[[str, bool], bool]]
+///     Called with `(error_message, is_temporary)` for a non-`NotFound`
+///     error. Return `True` to fall through to the next source, `False` to
+///     propagate immediately. Defaults to falling through on temporary
+///     errors only, so a flaky primary doesn't mask a healthy secondary
+///     while a permanent error on a healthy primary still surfaces.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "FallbackOperator")]
+pub struct PyFallbackOperator {
+    operators: Vec<ocore::blocking::Operator>,
+    write_all: bool,
+    fallback_predicate: Option<Py<PyAny>>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFallbackOperator {
+    #[new]
+    #[pyo3(signature = (operators, write_all=false, fallback_predicate=None))]
+    fn new(
+        operators: Vec<Bound<PyCapsule>>,
+        write_all: bool,
+        fallback_predicate: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let operators = capsules_to_operators(operators)?
+            .into_iter()
+            .map(ocore::blocking::Operator::new)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(Self {
+            operators,
+            write_all,
+            fallback_predicate,
+        })
+    }
+
+    /// Read `path`, trying each source in priority order until one succeeds.
+    fn read(&self, py: Python<'_>, path: &str) -> PyResult<Buffer> {
+        let mut last_err = None;
+        for op in &self.operators {
+            match op.read(path) {
+                Ok(buf) => return Ok(Buffer::new(buf.to_vec())),
+                Err(err) => {
+                    if !should_fallback(py, &err, self.fallback_predicate.as_ref())? {
+                        return Err(PyValueError::new_err(err.to_string()));
+                    }
+                    last_err = Some(err);
+                }
+            }
+        }
+        Err(not_found_or(path, last_err))
+    }
+
+    /// Stat `path`, trying each source in priority order until one succeeds.
+    ///
+    /// Returns a plain `dict` of the common metadata fields until a
+    /// dedicated stub-generated `Metadata` wrapper exists for this crate.
+    fn stat(&self, py: Python<'_>, path: &str) -> PyResult<Py<PyAny>> {
+        let mut last_err = None;
+        for op in &self.operators {
+            match op.stat(path) {
+                Ok(meta) => {
+                    let dict = pyo3::types::PyDict::new(py);
+                    dict.set_item("content_length", meta.content_length())?;
+                    dict.set_item("is_dir", meta.is_dir())?;
+                    dict.set_item("mode", format!("{:?}", meta.mode()))?;
+                    return Ok(dict.into());
+                }
+                Err(err) => {
+                    if !should_fallback(py, &err, self.fallback_predicate.as_ref())? {
+                        return Err(PyValueError::new_err(err.to_string()));
+                    }
+                    last_err = Some(err);
+                }
+            }
+        }
+        Err(not_found_or(path, last_err))
+    }
+
+    /// List `path`, merging entries from every reachable source.
+    ///
+    /// Entries are de-duplicated by path, preferring the higher-priority
+    /// source whenever more than one source has the same path.
+    fn list(&self, py: Python<'_>, path: &str) -> PyResult<Vec<String>> {
+        let mut merged: BTreeMap<String, ()> = BTreeMap::new();
+        let mut any_succeeded = false;
+        let mut last_err = None;
+
+        for op in &self.operators {
+            match op.list(path) {
+                Ok(entries) => {
+                    any_succeeded = true;
+                    for entry in entries {
+                        merged.entry(entry.path().to_string()).or_insert(());
+                    }
+                }
+                Err(err) => {
+                    if !should_fallback(py, &err, self.fallback_predicate.as_ref())? {
+                        return Err(PyValueError::new_err(err.to_string()));
+                    }
+                    last_err = Some(err);
+                }
+            }
+        }
+
+        if !any_succeeded {
+            return Err(not_found_or(path, last_err));
+        }
+        Ok(merged.into_keys().collect())
+    }
+
+    /// Write `bytes_` to `path`.
+    ///
+    /// Targets only the primary operator unless `write_all` was set at
+    /// construction, in which case every member operator is written to.
+    fn write(&self, path: &str, bytes_: Vec<u8>) -> PyResult<()> {
+        if !self.write_all {
+            return self.operators[0]
+                .write(path, bytes_)
+                .map_err(|err| PyValueError::new_err(err.to_string()));
+        }
+
+        for op in &self.operators {
+            op.write(path, bytes_.clone())
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Delete `path`.
+    ///
+    /// Targets only the primary operator unless `write_all` was set at
+    /// construction, in which case the delete is fanned out to every member
+    /// operator.
+    fn delete(&self, path: &str) -> PyResult<()> {
+        if !self.write_all {
+            return self.operators[0]
+                .delete(path)
+                .map_err(|err| PyValueError::new_err(err.to_string()));
+        }
+
+        for op in &self.operators {
+            op.delete(path)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+fn not_found_or(path: &str, last_err: Option<ocore::Error>) -> PyErr {
+    match last_err {
+        Some(err) if err.kind() != ocore::ErrorKind::NotFound => {
+            PyValueError::new_err(err.to_string())
+        }
+        _ => PyValueError::new_err(format!("{path} not found in any fallback source")),
+    }
+}
+
+/// Async counterpart of [`PyFallbackOperator`], built from
+/// `opendal.AsyncOperator` capsules instead of blocking ones.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "FallbackAsyncOperator")]
+pub struct PyFallbackAsyncOperator {
+    operators: Vec<ocore::Operator>,
+    write_all: bool,
+    fallback_predicate: Option<Py<PyAny>>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyFallbackAsyncOperator {
+    #[new]
+    #[pyo3(signature = (operators, write_all=false, fallback_predicate=None))]
+    fn new(
+        operators: Vec<Bound<PyCapsule>>,
+        write_all: bool,
+        fallback_predicate: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            operators: capsules_to_operators(operators)?,
+            write_all,
+            fallback_predicate,
+        })
+    }
+
+    /// Read `path`, trying each source in priority order until one succeeds.
+    fn read<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
+        let operators = self.operators.clone();
+        let predicate = self.fallback_predicate.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut last_err = None;
+            for op in &operators {
+                match op.read(&path).await {
+                    Ok(buf) => return Ok(Buffer::new(buf.to_vec())),
+                    Err(err) => {
+                        let fall_through =
+                            Python::with_gil(|py| should_fallback(py, &err, predicate.as_ref()))?;
+                        if !fall_through {
+                            return Err(PyValueError::new_err(err.to_string()));
+                        }
+                        last_err = Some(err);
+                    }
+                }
+            }
+            Err(not_found_or(&path, last_err))
+        })
+    }
+
+    /// Stat `path`, trying each source in priority order until one succeeds.
+    ///
+    /// Returns a plain `dict` of the common metadata fields until a
+    /// dedicated stub-generated `Metadata` wrapper exists for this crate.
+    fn stat<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
+        let operators = self.operators.clone();
+        let predicate = self.fallback_predicate.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut last_err = None;
+            for op in &operators {
+                match op.stat(&path).await {
+                    Ok(meta) => {
+                        return Python::with_gil(|py| -> PyResult<Py<PyAny>> {
+                            let dict = pyo3::types::PyDict::new(py);
+                            dict.set_item("content_length", meta.content_length())?;
+                            dict.set_item("is_dir", meta.is_dir())?;
+                            dict.set_item("mode", format!("{:?}", meta.mode()))?;
+                            Ok(dict.into())
+                        });
+                    }
+                    Err(err) => {
+                        let fall_through =
+                            Python::with_gil(|py| should_fallback(py, &err, predicate.as_ref()))?;
+                        if !fall_through {
+                            return Err(PyValueError::new_err(err.to_string()));
+                        }
+                        last_err = Some(err);
+                    }
+                }
+            }
+            Err(not_found_or(&path, last_err))
+        })
+    }
+
+    /// List `path`, merging entries from every reachable source.
+    ///
+    /// Entries are de-duplicated by path, preferring the higher-priority
+    /// source whenever more than one source has the same path.
+    fn list<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
+        let operators = self.operators.clone();
+        let predicate = self.fallback_predicate.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut merged: BTreeMap<String, ()> = BTreeMap::new();
+            let mut any_succeeded = false;
+            let mut last_err = None;
+
+            for op in &operators {
+                match op.list(&path).await {
+                    Ok(entries) => {
+                        any_succeeded = true;
+                        for entry in entries {
+                            merged.entry(entry.path().to_string()).or_insert(());
+                        }
+                    }
+                    Err(err) => {
+                        let fall_through =
+                            Python::with_gil(|py| should_fallback(py, &err, predicate.as_ref()))?;
+                        if !fall_through {
+                            return Err(PyValueError::new_err(err.to_string()));
+                        }
+                        last_err = Some(err);
+                    }
+                }
+            }
+
+            if !any_succeeded {
+                return Err(not_found_or(&path, last_err));
+            }
+            Ok(merged.into_keys().collect::<Vec<_>>())
+        })
+    }
+
+    /// Write `bytes_` to `path`.
+    ///
+    /// Targets only the primary operator unless `write_all` was set at
+    /// construction, in which case every member operator is written to.
+    fn write<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+        bytes_: Vec<u8>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let operators = self.operators.clone();
+        let write_all = self.write_all;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if !write_all {
+                operators[0]
+                    .write(&path, bytes_)
+                    .await
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+                return Ok(());
+            }
+
+            for op in &operators {
+                op.write(&path, bytes_.clone())
+                    .await
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Delete `path`.
+    ///
+    /// Targets only the primary operator unless `write_all` was set at
+    /// construction, in which case the delete is fanned out to every member
+    /// operator.
+    fn delete<'py>(&self, py: Python<'py>, path: String) -> PyResult<Bound<'py, PyAny>> {
+        let operators = self.operators.clone();
+        let write_all = self.write_all;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if !write_all {
+                operators[0]
+                    .delete(&path)
+                    .await
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+                return Ok(());
+            }
+
+            for op in &operators {
+                op.delete(&path)
+                    .await
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+            }
+            Ok(())
+        })
+    }
+}
@@ -17,6 +17,7 @@
 
 use std::ffi::{CStr, CString};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyCapsuleMethods};
 
@@ -26,17 +27,54 @@ use crate::ocore;
 const OPENDAL_OPERATOR_CAPSULE_NAME: &CStr = c"opendal_operator";
 const OPENDAL_LAYER_CAPSULE_NAME: &CStr = c"opendal_layer";
 
-/// Export an [`ocore::Operator`] to a PyCapsule.
+/// ABI version carried by every operator capsule's payload (see
+/// [`VersionedOperator`]). Out-of-tree extension modules registered through
+/// [`crate::register_service`] produce capsules with whatever version the
+/// `pyo3-opendal` release they linked against defines; bump this whenever the
+/// payload layout, or what a consumer is allowed to assume about the wrapped
+/// [`ocore::Operator`], changes incompatibly. [`from_operator_capsule`]
+/// rejects any version it doesn't understand rather than transmuting a
+/// capsule produced by an incompatible build.
+pub const OPERATOR_CAPSULE_ABI_VERSION: u32 = 1;
+
+/// Payload stored behind [`OPENDAL_OPERATOR_CAPSULE_NAME`].
+struct VersionedOperator {
+    abi_version: u32,
+    op: ocore::Operator,
+}
+
+/// Export an [`ocore::Operator`] to a PyCapsule, tagged with
+/// [`OPERATOR_CAPSULE_ABI_VERSION`].
 pub fn to_operator_capsule(py: Python, op: ocore::Operator) -> PyResult<Bound<PyCapsule>> {
-    PyCapsule::new(py, op, Some(CString::from(OPENDAL_OPERATOR_CAPSULE_NAME)))
+    let payload = VersionedOperator {
+        abi_version: OPERATOR_CAPSULE_ABI_VERSION,
+        op,
+    };
+    PyCapsule::new(
+        py,
+        payload,
+        Some(CString::from(OPENDAL_OPERATOR_CAPSULE_NAME)),
+    )
 }
 
-/// Import an [`ocore::Operator`] from a PyCapsule.
+/// Import an [`ocore::Operator`] from a PyCapsule, rejecting one tagged with
+/// an ABI version this build of `pyo3-opendal` doesn't understand (e.g. one
+/// produced by a third-party service extension compiled against a different
+/// release).
 pub fn from_operator_capsule(capsule: &Bound<PyCapsule>) -> PyResult<ocore::Operator> {
     let ptr = capsule
         .pointer_checked(Some(OPENDAL_OPERATOR_CAPSULE_NAME))?
-        .cast::<ocore::Operator>();
-    Ok(unsafe { ptr.as_ref().clone() })
+        .cast::<VersionedOperator>();
+    let payload = unsafe { ptr.as_ref() };
+
+    if payload.abi_version != OPERATOR_CAPSULE_ABI_VERSION {
+        return Err(PyValueError::new_err(format!(
+            "incompatible opendal operator capsule: expected ABI version {}, got {}",
+            OPERATOR_CAPSULE_ABI_VERSION, payload.abi_version
+        )));
+    }
+
+    Ok(payload.op.clone())
 }
 
 /// Export a [`Box<dyn PythonLayer>`] to a PyCapsule.
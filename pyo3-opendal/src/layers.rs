@@ -18,19 +18,32 @@
 use crate::*;
 use ocore::Operator;
 use pin_project::pin_project;
-use pyo3::types::PyCapsule;
+use pyo3::types::{PyCapsule, PyDict};
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
+use std::time::Instant;
 
 pub trait PythonLayer: Send + Sync {
     fn layer(&self, op: Operator) -> Operator;
+
+    /// The cancellation token in-flight operations created through this layer
+    /// race against, if any. Only [`PyRuntimeLayer`] currently carries one,
+    /// since it's the layer that actually drives every operation's future;
+    /// other layers default to `None`.
+    fn cancellation_token(&self) -> Option<tokio_util::sync::CancellationToken> {
+        None
+    }
 }
 
 impl PythonLayer for PyRuntimeLayer {
     fn layer(&self, op: Operator) -> Operator {
         op.layer(self.clone())
     }
+
+    fn cancellation_token(&self) -> Option<tokio_util::sync::CancellationToken> {
+        Some(self.token.clone())
+    }
 }
 
 /// Layers are used to intercept the operations on the underlying storage.
@@ -42,10 +55,8 @@ pub struct PyLayer(pub Box<dyn PythonLayer>);
 #[pymethods]
 impl PyLayer {
     #[new]
-    pub fn new() -> PyResult<Self> {
-        let runtime = pyo3_async_runtimes::tokio::get_runtime();
-        let handle = runtime.handle().clone();
-        Ok(Self(Box::new(PyRuntimeLayer::new(handle))))
+    pub fn new(py: Python) -> PyResult<Self> {
+        Ok(Self(Box::new(PyRuntimeLayer::shared(py)?)))
     }
 
     /// Apply the layer to an operator (passed as capsule) and return a new operator (as capsule).
@@ -59,6 +70,49 @@ impl PyLayer {
         let new_op = self.0.layer(op);
         crate::ffi::to_operator_capsule(py, new_op)
     }
+
+    /// Returns the [`PyDriver`] that can stop any in-flight operation created
+    /// through this layer. Every `Layer` carries one, since the Python-facing
+    /// `Layer()` base class is itself a [`PyRuntimeLayer`] underneath (even
+    /// subclasses like `RetryLayer`/`LoggingLayer` inherit it), and
+    /// `PyRuntimeLayer` is the layer that actually drives operation futures.
+    fn driver(&self) -> PyDriver {
+        PyDriver {
+            token: self
+                .0
+                .cancellation_token()
+                .expect("base Layer is always backed by a PyRuntimeLayer"),
+        }
+    }
+}
+
+/// A stop handle for in-flight operations driven through a [`PyLayer`].
+///
+/// Obtained via `Layer.driver()`, a `Driver` can be handed to a background
+/// thread or a signal handler: calling [`PyDriver::stop`] cancels every
+/// operation currently running (or about to run) on the operator the layer
+/// was applied to, causing them to fail with an `Interrupted` error on their
+/// next poll.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal.layers", name = "Driver", frozen)]
+pub struct PyDriver {
+    token: tokio_util::sync::CancellationToken,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyDriver {
+    /// Cancels every operation currently in flight (or started afterwards)
+    /// through the operator this driver was obtained from.
+    fn stop(&self) {
+        self.token.cancel();
+    }
+
+    /// Whether [`PyDriver::stop`] has already been called.
+    #[getter]
+    fn stopped(&self) -> bool {
+        self.token.is_cancelled()
+    }
 }
 
 /// A layer that enters a Tokio runtime context before delegating to the inner accessor.
@@ -87,17 +141,32 @@ impl PyLayer {
 ///
 /// *   **Micro-level:** There is a negligible CPU overhead (nanoseconds) per poll cycle due
 ///     to Thread-Local Storage (TLS) context switching.
-/// *   **Macro-level:** Since each Service extension initializes its own Tokio runtime,
-///     loading multiple services results in multiple thread pools running in the background.
-///     This increases resource usage (threads, memory) compared to a monolithic build.
+/// *   **Macro-level:** Each Service extension used to initialize its own Tokio runtime, so
+///     loading multiple services spun up multiple thread pools in the background. Callers
+///     should now build this layer via [`PyRuntimeLayer::shared`], which publishes (or
+///     reuses) a single process-wide runtime handle through [`crate::runtime_registry`],
+///     collapsing those N thread pools back into one.
 #[derive(Debug, Clone)]
 pub struct PyRuntimeLayer {
     handle: tokio::runtime::Handle,
+    token: tokio_util::sync::CancellationToken,
 }
 
 impl PyRuntimeLayer {
     pub fn new(handle: tokio::runtime::Handle) -> Self {
-        Self { handle }
+        Self {
+            handle,
+            token: tokio_util::sync::CancellationToken::new(),
+        }
+    }
+
+    /// Builds a `PyRuntimeLayer` from the process-wide [`shared_runtime_handle`],
+    /// falling back to creating a local runtime only when no shared handle has
+    /// been registered yet (see [`crate::runtime_registry`]).
+    pub fn shared(py: Python) -> PyResult<Self> {
+        Ok(Self::new(crate::runtime_registry::shared_runtime_handle(
+            py,
+        )?))
     }
 }
 
@@ -108,6 +177,7 @@ impl<A: ocore::raw::Access> ocore::raw::Layer<A> for PyRuntimeLayer {
         RuntimeAccessor {
             inner,
             handle: self.handle.clone(),
+            token: self.token.clone(),
         }
     }
 }
@@ -116,6 +186,16 @@ impl<A: ocore::raw::Access> ocore::raw::Layer<A> for PyRuntimeLayer {
 pub struct RuntimeAccessor<A> {
     inner: A,
     handle: tokio::runtime::Handle,
+    token: tokio_util::sync::CancellationToken,
+}
+
+/// Returns the `Interrupted` error every in-flight `RuntimeFuture`/
+/// `RuntimeWrapper` call resolves to once its [`PyDriver`] has been stopped.
+fn cancelled_error() -> ocore::Error {
+    ocore::Error::new(
+        ocore::ErrorKind::Interrupted,
+        "operation cancelled via Driver.stop()",
+    )
 }
 
 #[pin_project]
@@ -123,13 +203,32 @@ pub struct RuntimeFuture<F> {
     #[pin]
     fut: F,
     handle: tokio::runtime::Handle,
+    #[pin]
+    cancelled: tokio_util::sync::WaitForCancellationFutureOwned,
+}
+
+impl<F> RuntimeFuture<F> {
+    fn new(
+        fut: F,
+        handle: tokio::runtime::Handle,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Self {
+        Self {
+            fut,
+            handle,
+            cancelled: token.cancelled_owned(),
+        }
+    }
 }
 
-impl<F: Future> Future for RuntimeFuture<F> {
-    type Output = F::Output;
+impl<T, F: Future<Output = ocore::Result<T>>> Future for RuntimeFuture<F> {
+    type Output = ocore::Result<T>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.project();
+        if this.cancelled.poll(cx).is_ready() {
+            return Poll::Ready(Err(cancelled_error()));
+        }
         let _guard = this.handle.enter();
         this.fut.poll(cx)
     }
@@ -152,16 +251,13 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpRead,
     ) -> ocore::Result<(ocore::raw::RpRead, Self::Reader)> {
         let fut = self.inner.read(path, args);
-        let (rp, reader) = RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await?;
+        let (rp, reader) = RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await?;
         Ok((
             rp,
             RuntimeWrapper {
                 inner: reader,
                 handle: self.handle.clone(),
+                token: self.token.clone(),
             },
         ))
     }
@@ -172,16 +268,13 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpWrite,
     ) -> ocore::Result<(ocore::raw::RpWrite, Self::Writer)> {
         let fut = self.inner.write(path, args);
-        let (rp, writer) = RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await?;
+        let (rp, writer) = RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await?;
         Ok((
             rp,
             RuntimeWrapper {
                 inner: writer,
                 handle: self.handle.clone(),
+                token: self.token.clone(),
             },
         ))
     }
@@ -192,32 +285,26 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpList,
     ) -> ocore::Result<(ocore::raw::RpList, Self::Lister)> {
         let fut = self.inner.list(path, args);
-        let (rp, lister) = RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await?;
+        let (rp, lister) = RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await?;
         Ok((
             rp,
             RuntimeWrapper {
                 inner: lister,
                 handle: self.handle.clone(),
+                token: self.token.clone(),
             },
         ))
     }
 
     async fn delete(&self) -> ocore::Result<(ocore::raw::RpDelete, Self::Deleter)> {
         let fut = self.inner.delete();
-        let (rp, deleter) = RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await?;
+        let (rp, deleter) = RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await?;
         Ok((
             rp,
             RuntimeWrapper {
                 inner: deleter,
                 handle: self.handle.clone(),
+                token: self.token.clone(),
             },
         ))
     }
@@ -228,11 +315,7 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpPresign,
     ) -> ocore::Result<ocore::raw::RpPresign> {
         let fut = self.inner.presign(path, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn stat(
@@ -241,11 +324,7 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpStat,
     ) -> ocore::Result<ocore::raw::RpStat> {
         let fut = self.inner.stat(path, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn create_dir(
@@ -254,11 +333,7 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpCreateDir,
     ) -> ocore::Result<ocore::raw::RpCreateDir> {
         let fut = self.inner.create_dir(path, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn copy(
@@ -268,11 +343,7 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpCopy,
     ) -> ocore::Result<ocore::raw::RpCopy> {
         let fut = self.inner.copy(from, to, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn rename(
@@ -282,86 +353,724 @@ impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for RuntimeAccessor<A> {
         args: ocore::raw::OpRename,
     ) -> ocore::Result<ocore::raw::RpRename> {
         let fut = self.inner.rename(from, to, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 }
 
 pub struct RuntimeWrapper<T> {
     inner: T,
     handle: tokio::runtime::Handle,
+    token: tokio_util::sync::CancellationToken,
 }
 
 impl<T: ocore::raw::oio::Read> ocore::raw::oio::Read for RuntimeWrapper<T> {
     async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
         let fut = self.inner.read();
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 }
 
 impl<T: ocore::raw::oio::Write> ocore::raw::oio::Write for RuntimeWrapper<T> {
     async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
         let fut = self.inner.write(bs);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
         let fut = self.inner.close();
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn abort(&mut self) -> ocore::Result<()> {
         let fut = self.inner.abort();
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 }
 
 impl<T: ocore::raw::oio::List> ocore::raw::oio::List for RuntimeWrapper<T> {
     async fn next(&mut self) -> ocore::Result<Option<ocore::raw::oio::Entry>> {
         let fut = self.inner.next();
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 }
 
 impl<T: ocore::raw::oio::Delete> ocore::raw::oio::Delete for RuntimeWrapper<T> {
     async fn delete(&mut self, path: &str, args: ocore::raw::OpDelete) -> ocore::Result<()> {
         let fut = self.inner.delete(path, args);
-        RuntimeFuture {
-            fut,
-            handle: self.handle.clone(),
-        }
-        .await
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
     }
 
     async fn close(&mut self) -> ocore::Result<()> {
         let fut = self.inner.close();
-        RuntimeFuture {
-            fut,
+        RuntimeFuture::new(fut, self.handle.clone(), self.token.clone()).await
+    }
+}
+
+/// Emits one structured event to a user-supplied Python callback for an
+/// operation boundary `PyLoggingLayer` observed.
+///
+/// Acquires the GIL for the duration of the call only; callers must make
+/// sure this runs after any Tokio reactor guard (e.g. `PyRuntimeLayer`'s
+/// `handle.enter()`) has already been dropped, since acquiring the GIL while
+/// holding one can deadlock against a Python thread blocked on that same
+/// reactor.
+fn emit_logging_event(
+    callback: &Py<PyAny>,
+    operation: &'static str,
+    path: &str,
+    elapsed: std::time::Duration,
+    bytes: Option<u64>,
+    body: Option<&[u8]>,
+    error: Option<&ocore::Error>,
+) {
+    Python::with_gil(|py| {
+        let event = PyDict::new(py);
+        let _ = event.set_item("operation", operation);
+        let _ = event.set_item("path", path);
+        let _ = event.set_item("elapsed_secs", elapsed.as_secs_f64());
+        let _ = event.set_item("bytes", bytes);
+        let _ = event.set_item("success", error.is_none());
+        if let Some(err) = error {
+            let _ = event.set_item("error", err.to_string());
+        }
+        if let Some(body) = body {
+            let _ = event.set_item("body", pyo3::types::PyBytes::new(py, body));
+        }
+        if let Err(err) = callback.bind(py).call1((event,)) {
+            err.print(py);
+        }
+    });
+}
+
+/// A layer that forwards every operation it observes to a user-supplied
+/// Python callable, for routing into `logging`, metrics, or tracing.
+///
+/// Logging of request/response bodies is opt-in via `log_bodies` (`false` by
+/// default), since buffering a full read/write body in memory to hand to the
+/// callback is not free.
+#[derive(Clone)]
+pub struct LoggingLayer {
+    callback: std::sync::Arc<Py<PyAny>>,
+    log_bodies: bool,
+}
+
+impl LoggingLayer {
+    pub fn new(callback: Py<PyAny>, log_bodies: bool) -> Self {
+        Self {
+            callback: std::sync::Arc::new(callback),
+            log_bodies,
+        }
+    }
+}
+
+impl<A: ocore::raw::Access> ocore::raw::Layer<A> for LoggingLayer {
+    type LayeredAccess = LoggingAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        LoggingAccessor {
+            inner,
+            callback: self.callback.clone(),
+            log_bodies: self.log_bodies,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LoggingAccessor<A> {
+    inner: A,
+    callback: std::sync::Arc<Py<PyAny>>,
+    log_bodies: bool,
+}
+
+impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for LoggingAccessor<A> {
+    type Inner = A;
+    type Reader = LoggingWrapper<A::Reader>;
+    type Writer = LoggingWrapper<A::Writer>;
+    type Lister = A::Lister;
+    type Deleter = A::Deleter;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(
+        &self,
+        path: &str,
+        args: ocore::raw::OpRead,
+    ) -> ocore::Result<(ocore::raw::RpRead, Self::Reader)> {
+        let started = Instant::now();
+        match self.inner.read(path, args).await {
+            Ok((rp, reader)) => Ok((
+                rp,
+                LoggingWrapper::new(reader, "read", path, self.callback.clone(), self.log_bodies),
+            )),
+            Err(err) => {
+                emit_logging_event(&self.callback, "read", path, started.elapsed(), None, None, Some(&err));
+                Err(err)
+            }
+        }
+    }
+
+    async fn write(
+        &self,
+        path: &str,
+        args: ocore::raw::OpWrite,
+    ) -> ocore::Result<(ocore::raw::RpWrite, Self::Writer)> {
+        let started = Instant::now();
+        match self.inner.write(path, args).await {
+            Ok((rp, writer)) => Ok((
+                rp,
+                LoggingWrapper::new(writer, "write", path, self.callback.clone(), self.log_bodies),
+            )),
+            Err(err) => {
+                emit_logging_event(&self.callback, "write", path, started.elapsed(), None, None, Some(&err));
+                Err(err)
+            }
+        }
+    }
+
+    async fn list(
+        &self,
+        path: &str,
+        args: ocore::raw::OpList,
+    ) -> ocore::Result<(ocore::raw::RpList, Self::Lister)> {
+        let started = Instant::now();
+        let result = self.inner.list(path, args).await;
+        self.log_boundary("list", path, started.elapsed(), &result);
+        result
+    }
+
+    async fn delete(&self) -> ocore::Result<(ocore::raw::RpDelete, Self::Deleter)> {
+        let started = Instant::now();
+        let result = self.inner.delete().await;
+        self.log_boundary("delete", "", started.elapsed(), &result);
+        result
+    }
+
+    async fn stat(
+        &self,
+        path: &str,
+        args: ocore::raw::OpStat,
+    ) -> ocore::Result<ocore::raw::RpStat> {
+        let started = Instant::now();
+        let result = self.inner.stat(path, args).await;
+        self.log_boundary("stat", path, started.elapsed(), &result);
+        result
+    }
+
+    async fn copy(
+        &self,
+        from: &str,
+        to: &str,
+        args: ocore::raw::OpCopy,
+    ) -> ocore::Result<ocore::raw::RpCopy> {
+        let started = Instant::now();
+        let result = self.inner.copy(from, to, args).await;
+        self.log_boundary("copy", &format!("{from} -> {to}"), started.elapsed(), &result);
+        result
+    }
+
+    async fn rename(
+        &self,
+        from: &str,
+        to: &str,
+        args: ocore::raw::OpRename,
+    ) -> ocore::Result<ocore::raw::RpRename> {
+        let started = Instant::now();
+        let result = self.inner.rename(from, to, args).await;
+        self.log_boundary("rename", &format!("{from} -> {to}"), started.elapsed(), &result);
+        result
+    }
+}
+
+impl<A> LoggingAccessor<A> {
+    /// Emits a logging event for an operation with no streaming body
+    /// (`list`/`stat`/`delete`/`copy`/`rename`), given its already-awaited result.
+    fn log_boundary<T>(
+        &self,
+        operation: &'static str,
+        path: &str,
+        elapsed: std::time::Duration,
+        result: &ocore::Result<T>,
+    ) {
+        emit_logging_event(
+            &self.callback,
+            operation,
+            path,
+            elapsed,
+            None,
+            None,
+            result.as_ref().err(),
+        );
+    }
+}
+
+/// Wraps a `Reader`/`Writer` to accumulate the bytes (and, when `log_bodies`
+/// is set, the raw content) that flow through it, emitting one logging event
+/// covering the whole read/write once it completes.
+pub struct LoggingWrapper<T> {
+    inner: T,
+    operation: &'static str,
+    path: String,
+    callback: std::sync::Arc<Py<PyAny>>,
+    log_bodies: bool,
+    started: Instant,
+    bytes: u64,
+    body: Vec<u8>,
+}
+
+impl<T> LoggingWrapper<T> {
+    fn new(
+        inner: T,
+        operation: &'static str,
+        path: &str,
+        callback: std::sync::Arc<Py<PyAny>>,
+        log_bodies: bool,
+    ) -> Self {
+        Self {
+            inner,
+            operation,
+            path: path.to_string(),
+            callback,
+            log_bodies,
+            started: Instant::now(),
+            bytes: 0,
+            body: Vec::new(),
+        }
+    }
+
+    fn emit(&self, error: Option<&ocore::Error>) {
+        emit_logging_event(
+            &self.callback,
+            self.operation,
+            &self.path,
+            self.started.elapsed(),
+            Some(self.bytes),
+            self.log_bodies.then_some(self.body.as_slice()),
+            error,
+        );
+    }
+}
+
+impl<T: ocore::raw::oio::Read> ocore::raw::oio::Read for LoggingWrapper<T> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        match self.inner.read().await {
+            Ok(buf) => {
+                if buf.is_empty() {
+                    self.emit(None);
+                } else {
+                    self.bytes += buf.len() as u64;
+                    if self.log_bodies {
+                        self.body.extend(buf.to_vec());
+                    }
+                }
+                Ok(buf)
+            }
+            Err(err) => {
+                self.emit(Some(&err));
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T: ocore::raw::oio::Write> ocore::raw::oio::Write for LoggingWrapper<T> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        self.bytes += bs.len() as u64;
+        if self.log_bodies {
+            self.body.extend(bs.to_vec());
+        }
+        self.inner.write(bs).await
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        match self.inner.close().await {
+            Ok(metadata) => {
+                self.emit(None);
+                Ok(metadata)
+            }
+            Err(err) => {
+                self.emit(Some(&err));
+                Err(err)
+            }
+        }
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        self.inner.abort().await
+    }
+}
+
+/// Layer that forwards OpenDAL operation events to a Python callback (see
+/// `logging.Logger.info` or similar), for observing what actually hits
+/// storage.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal.layers", name = "LoggingLayer", extends=PyLayer)]
+pub struct PyLoggingLayer {
+    l: LoggingLayer,
+}
+
+impl PythonLayer for PyLoggingLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.l.clone())
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyLoggingLayer {
+    /// Create a new LoggingLayer.
+    ///
+    /// Parameters
+    /// ----------
+    /// callback : Callable[[dict], None]
+    ///     Called once per operation boundary with a `dict` containing
+    ///     `operation`, `path`, `elapsed_secs`, `bytes`, `success`, and
+    ///     (on failure) `error`. Never invoked while a Tokio reactor guard is
+    ///     held, so it's safe to do blocking Python work (e.g. `logging`)
+    ///     inside it.
+    /// log_bodies : bool
+    ///     When `True`, also includes a `body` field with the raw bytes
+    ///     read/written. Off by default, since buffering a full body costs
+    ///     memory proportional to transfer size.
+    ///
+    /// Returns
+    /// -------
+    /// LoggingLayer
+    #[gen_stub(override_return_type(type_repr = "LoggingLayer"))]
+    #[new]
+    #[pyo3(signature = (callback, log_bodies = false))]
+    fn new(py: Python, callback: Py<PyAny>, log_bodies: bool) -> PyResult<PyClassInitializer<Self>> {
+        let logging_layer = Self {
+            l: LoggingLayer::new(callback, log_bodies),
+        };
+        let class = PyClassInitializer::from(PyLayer::new(py)?).add_subclass(logging_layer);
+
+        Ok(class)
+    }
+}
+
+/// Returns the error every `TimeoutFuture` resolves to once its deadline
+/// elapses before the wrapped future/poll does.
+fn timeout_error() -> ocore::Error {
+    ocore::Error::new(ocore::ErrorKind::Unexpected, "operation timed out")
+}
+
+/// A layer that enforces a deadline on every operation future and every
+/// `Reader`/`Writer`/`Lister`/`Deleter` poll it drives, aborting the call and
+/// returning a timeout error once the deadline elapses.
+///
+/// Like [`PyRuntimeLayer`], this layer carries its own captured
+/// `tokio::runtime::Handle`: a `tokio::time::Sleep` has to be created (and
+/// polled) inside the runtime whose timer wheel is expected to drive it, and
+/// that's the Service binary's runtime, not whichever binary happens to be
+/// polling the future (see [`PyRuntimeLayer`]'s doc comment for the full
+/// split Core/Service explanation).
+#[derive(Debug, Clone)]
+pub struct TimeoutLayer {
+    handle: tokio::runtime::Handle,
+    timeout: Option<std::time::Duration>,
+    io_timeout: Option<std::time::Duration>,
+}
+
+impl TimeoutLayer {
+    pub fn new(
+        handle: tokio::runtime::Handle,
+        timeout: Option<std::time::Duration>,
+        io_timeout: Option<std::time::Duration>,
+    ) -> Self {
+        Self {
+            handle,
+            timeout,
+            io_timeout,
+        }
+    }
+}
+
+impl<A: ocore::raw::Access> ocore::raw::Layer<A> for TimeoutLayer {
+    type LayeredAccess = TimeoutAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccess {
+        TimeoutAccessor {
+            inner,
             handle: self.handle.clone(),
+            timeout: self.timeout,
+            io_timeout: self.io_timeout,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TimeoutAccessor<A> {
+    inner: A,
+    handle: tokio::runtime::Handle,
+    timeout: Option<std::time::Duration>,
+    io_timeout: Option<std::time::Duration>,
+}
+
+#[pin_project]
+pub struct TimeoutFuture<F> {
+    #[pin]
+    fut: F,
+    handle: tokio::runtime::Handle,
+    #[pin]
+    sleep: Option<tokio::time::Sleep>,
+}
+
+impl<F> TimeoutFuture<F> {
+    fn new(fut: F, handle: tokio::runtime::Handle, duration: Option<std::time::Duration>) -> Self {
+        let sleep = {
+            let _guard = handle.enter();
+            duration.map(tokio::time::sleep)
+        };
+        Self { fut, handle, sleep }
+    }
+}
+
+impl<T, F: Future<Output = ocore::Result<T>>> Future for TimeoutFuture<F> {
+    type Output = ocore::Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _guard = this.handle.enter();
+        if let Some(sleep) = this.sleep.as_pin_mut() {
+            if sleep.poll(cx).is_ready() {
+                return Poll::Ready(Err(timeout_error()));
+            }
         }
-        .await
+        this.fut.poll(cx)
+    }
+}
+
+impl<A: ocore::raw::Access> ocore::raw::LayeredAccess for TimeoutAccessor<A> {
+    type Inner = A;
+    type Reader = TimeoutWrapper<A::Reader>;
+    type Writer = TimeoutWrapper<A::Writer>;
+    type Lister = TimeoutWrapper<A::Lister>;
+    type Deleter = TimeoutWrapper<A::Deleter>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    async fn read(
+        &self,
+        path: &str,
+        args: ocore::raw::OpRead,
+    ) -> ocore::Result<(ocore::raw::RpRead, Self::Reader)> {
+        let fut = self.inner.read(path, args);
+        let (rp, reader) = TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await?;
+        Ok((
+            rp,
+            TimeoutWrapper {
+                inner: reader,
+                handle: self.handle.clone(),
+                timeout: self.io_timeout,
+            },
+        ))
+    }
+
+    async fn write(
+        &self,
+        path: &str,
+        args: ocore::raw::OpWrite,
+    ) -> ocore::Result<(ocore::raw::RpWrite, Self::Writer)> {
+        let fut = self.inner.write(path, args);
+        let (rp, writer) = TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await?;
+        Ok((
+            rp,
+            TimeoutWrapper {
+                inner: writer,
+                handle: self.handle.clone(),
+                timeout: self.io_timeout,
+            },
+        ))
+    }
+
+    async fn list(
+        &self,
+        path: &str,
+        args: ocore::raw::OpList,
+    ) -> ocore::Result<(ocore::raw::RpList, Self::Lister)> {
+        let fut = self.inner.list(path, args);
+        let (rp, lister) = TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await?;
+        Ok((
+            rp,
+            TimeoutWrapper {
+                inner: lister,
+                handle: self.handle.clone(),
+                timeout: self.timeout,
+            },
+        ))
+    }
+
+    async fn delete(&self) -> ocore::Result<(ocore::raw::RpDelete, Self::Deleter)> {
+        let fut = self.inner.delete();
+        let (rp, deleter) = TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await?;
+        Ok((
+            rp,
+            TimeoutWrapper {
+                inner: deleter,
+                handle: self.handle.clone(),
+                timeout: self.timeout,
+            },
+        ))
+    }
+
+    async fn presign(
+        &self,
+        path: &str,
+        args: ocore::raw::OpPresign,
+    ) -> ocore::Result<ocore::raw::RpPresign> {
+        let fut = self.inner.presign(path, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn stat(
+        &self,
+        path: &str,
+        args: ocore::raw::OpStat,
+    ) -> ocore::Result<ocore::raw::RpStat> {
+        let fut = self.inner.stat(path, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn create_dir(
+        &self,
+        path: &str,
+        args: ocore::raw::OpCreateDir,
+    ) -> ocore::Result<ocore::raw::RpCreateDir> {
+        let fut = self.inner.create_dir(path, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn copy(
+        &self,
+        from: &str,
+        to: &str,
+        args: ocore::raw::OpCopy,
+    ) -> ocore::Result<ocore::raw::RpCopy> {
+        let fut = self.inner.copy(from, to, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn rename(
+        &self,
+        from: &str,
+        to: &str,
+        args: ocore::raw::OpRename,
+    ) -> ocore::Result<ocore::raw::RpRename> {
+        let fut = self.inner.rename(from, to, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+}
+
+/// Wraps a `Reader`/`Writer`/`Lister`/`Deleter`, applying `timeout` (the
+/// layer's `io_timeout` for streams, its whole-operation `timeout` for
+/// listers/deleters) to every call.
+pub struct TimeoutWrapper<T> {
+    inner: T,
+    handle: tokio::runtime::Handle,
+    timeout: Option<std::time::Duration>,
+}
+
+impl<T: ocore::raw::oio::Read> ocore::raw::oio::Read for TimeoutWrapper<T> {
+    async fn read(&mut self) -> ocore::Result<ocore::Buffer> {
+        let fut = self.inner.read();
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+}
+
+impl<T: ocore::raw::oio::Write> ocore::raw::oio::Write for TimeoutWrapper<T> {
+    async fn write(&mut self, bs: ocore::Buffer) -> ocore::Result<()> {
+        let fut = self.inner.write(bs);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn close(&mut self) -> ocore::Result<ocore::Metadata> {
+        let fut = self.inner.close();
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn abort(&mut self) -> ocore::Result<()> {
+        let fut = self.inner.abort();
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+}
+
+impl<T: ocore::raw::oio::List> ocore::raw::oio::List for TimeoutWrapper<T> {
+    async fn next(&mut self) -> ocore::Result<Option<ocore::raw::oio::Entry>> {
+        let fut = self.inner.next();
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+}
+
+impl<T: ocore::raw::oio::Delete> ocore::raw::oio::Delete for TimeoutWrapper<T> {
+    async fn delete(&mut self, path: &str, args: ocore::raw::OpDelete) -> ocore::Result<()> {
+        let fut = self.inner.delete(path, args);
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+
+    async fn close(&mut self) -> ocore::Result<()> {
+        let fut = self.inner.close();
+        TimeoutFuture::new(fut, self.handle.clone(), self.timeout).await
+    }
+}
+
+/// Layer that enforces per-operation and per-IO-chunk deadlines, aborting a
+/// call that runs longer than configured and returning a timeout error.
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal.layers", name = "TimeoutLayer", extends=PyLayer)]
+pub struct PyTimeoutLayer {
+    l: TimeoutLayer,
+}
+
+impl PythonLayer for PyTimeoutLayer {
+    fn layer(&self, op: Operator) -> Operator {
+        op.layer(self.l.clone())
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyTimeoutLayer {
+    /// Create a new TimeoutLayer.
+    ///
+    /// Parameters
+    /// ----------
+    /// timeout : Optional[float]
+    ///     Whole-operation deadline, in seconds, for metadata operations
+    ///     (`stat`/`list`/`delete`/`create_dir`/`copy`/`rename`/`presign`) and
+    ///     for establishing a `read`/`write` stream. `None` disables it.
+    ///     For `list` and `delete`, the deadline covers opening the lister/
+    ///     deleter and then, separately, each `next`/`delete`/`close` call
+    ///     on it; the total time of a multi-call listing or batch delete
+    ///     is not bounded.
+    /// io_timeout : Optional[float]
+    ///     Per-chunk deadline, in seconds, applied to each `read`/`write`
+    ///     call on an already-open stream. `None` disables it.
+    ///
+    /// Returns
+    /// -------
+    /// TimeoutLayer
+    #[gen_stub(override_return_type(type_repr = "TimeoutLayer"))]
+    #[new]
+    #[pyo3(signature = (timeout = None, io_timeout = None))]
+    fn new(
+        py: Python,
+        timeout: Option<f64>,
+        io_timeout: Option<f64>,
+    ) -> PyResult<PyClassInitializer<Self>> {
+        let handle = crate::runtime_registry::shared_runtime_handle(py)?;
+        let timeout_layer = Self {
+            l: TimeoutLayer::new(
+                handle,
+                timeout.map(std::time::Duration::from_secs_f64),
+                io_timeout.map(std::time::Duration::from_secs_f64),
+            ),
+        };
+        let class = PyClassInitializer::from(PyLayer::new(py)?).add_subclass(timeout_layer);
+
+        Ok(class)
     }
 }
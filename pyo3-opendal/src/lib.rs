@@ -43,6 +43,10 @@ mod operator;
 #[cfg(feature = "runtime")]
 pub use operator::*;
 #[cfg(feature = "runtime")]
+mod fallback;
+#[cfg(feature = "runtime")]
+pub use fallback::*;
+#[cfg(feature = "runtime")]
 mod file;
 #[cfg(feature = "runtime")]
 pub use file::*;
@@ -63,6 +67,14 @@ pub mod export;
 #[cfg(feature = "runtime")]
 pub mod ffi;
 #[cfg(feature = "runtime")]
+pub mod type_registry;
+#[cfg(feature = "runtime")]
+pub use type_registry::register_service;
+#[cfg(feature = "runtime")]
+pub mod runtime_registry;
+#[cfg(feature = "runtime")]
+pub use runtime_registry::shared_runtime_handle;
+#[cfg(feature = "runtime")]
 use pyo3_stub_gen::derive::*;
 
 pub mod codegen;
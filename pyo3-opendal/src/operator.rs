@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `opendal.Operator`/`opendal.AsyncOperator`: the real, shared base types
+//! every service's `to_operator()`/`to_async_operator()` converts into (see
+//! `crate::export::OpendalOperator`/`OpendalAsyncOperator`'s `IntoPyObject`
+//! impls, which call back into `_from_capsule` here) and that
+//! `crate::type_registry::initialize_shared_types` hands out as the one
+//! shared instance every loaded service builds on top of.
+//!
+//! This currently only wires up the versioned-object surface
+//! (`read_version`/`stat_version`/`delete_version`/`list_with_versions`) --
+//! the un-versioned `read`/`write`/`stat`/`delete`/`list`/capability surface
+//! these classes also need is tracked separately and not touched here.
+
+use std::collections::HashMap;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyType};
+use pyo3_stub_gen::derive::*;
+
+use crate::export::VersionedEntry;
+use crate::ffi::from_operator_capsule;
+use crate::ocore;
+
+/// Returns the error every method below raises when called on an operator
+/// that was built via [`PyOperator::new_empty`]/[`PyAsyncOperator::new_empty`]
+/// (the placeholder shared type instance) rather than `_from_capsule`.
+fn unbound_operator_err() -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+        "operator is not bound to a backing service; build one via a *Service's \
+         to_operator()/to_async_operator() instead of constructing this class directly",
+    )
+}
+
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "Operator")]
+pub struct PyOperator {
+    op: Option<ocore::blocking::Operator>,
+    map: HashMap<String, String>,
+}
+
+impl PyOperator {
+    /// The placeholder instance [`crate::type_registry::initialize_shared_types`]
+    /// publishes so every service module shares the exact same base type,
+    /// rather than each compiling its own copy of this class.
+    pub fn new_empty() -> Self {
+        Self {
+            op: None,
+            map: HashMap::new(),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyOperator {
+    /// Rebuilds an `Operator` from the `opendal_operator` PyCapsule a
+    /// service's `to_operator()` produced (see `crate::export::OpendalOperator`'s
+    /// `IntoPyObject` impl), the FFI handoff `crate::ffi` uses to move
+    /// operators between independently compiled extension modules.
+    #[classmethod]
+    #[pyo3(signature = (capsule, map))]
+    fn _from_capsule(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        capsule: Bound<'_, PyCapsule>,
+        map: HashMap<String, String>,
+    ) -> PyResult<Self> {
+        let op = from_operator_capsule(&capsule)?;
+        let handle = crate::runtime_registry::shared_runtime_handle(py)?;
+        let _guard = handle.enter();
+        let op = ocore::blocking::Operator::new(op)
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+        Ok(Self {
+            op: Some(op),
+            map,
+        })
+    }
+
+    /// Reads `path`, optionally a specific `version` rather than the current
+    /// one (only meaningful when the backing service has versioning enabled,
+    /// e.g. `S3Service.enable_versioning`).
+    #[pyo3(signature = (path, version=None))]
+    fn read_version(&self, path: &str, version: Option<&str>) -> PyResult<Vec<u8>> {
+        let op = self.op.as_ref().ok_or_else(unbound_operator_err)?;
+        let mut req = op.read_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        Ok(req
+            .call()
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?
+            .to_vec())
+    }
+
+    /// Stats `path`, optionally a specific `version`.
+    ///
+    /// Returns a plain `dict` of the common metadata fields until a
+    /// dedicated stub-generated `Metadata` wrapper is available here.
+    #[pyo3(signature = (path, version=None))]
+    fn stat_version(&self, py: Python<'_>, path: &str, version: Option<&str>) -> PyResult<Py<PyAny>> {
+        let op = self.op.as_ref().ok_or_else(unbound_operator_err)?;
+        let mut req = op.stat_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        let meta = req
+            .call()
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("content_length", meta.content_length())?;
+        dict.set_item("is_dir", meta.is_dir())?;
+        dict.set_item("mode", format!("{:?}", meta.mode()))?;
+        dict.set_item("version", meta.version())?;
+        Ok(dict.into())
+    }
+
+    /// Deletes `path`, optionally a specific `version` rather than inserting
+    /// a delete marker over the current one.
+    #[pyo3(signature = (path, version=None))]
+    fn delete_version(&self, path: &str, version: Option<&str>) -> PyResult<()> {
+        let op = self.op.as_ref().ok_or_else(unbound_operator_err)?;
+        let mut req = op.delete_with(path);
+        if let Some(v) = version {
+            req = req.version(v);
+        }
+        req.call()
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))
+    }
+
+    /// Lists every version of every object under `path`, annotated with its
+    /// version id and whether it's the current version of that path.
+    ///
+    /// Services that support versioning (S3, R2, OSS) return versions of a
+    /// given key most-recent-first, so the first time a path is seen here is
+    /// its current version.
+    fn list_with_versions(&self, path: &str) -> PyResult<Vec<VersionedEntry>> {
+        let op = self.op.as_ref().ok_or_else(unbound_operator_err)?;
+        let entries = op
+            .list_with(path)
+            .versions(true)
+            .call()
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+
+        let mut seen = std::collections::HashSet::new();
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let path = entry.path().to_string();
+                let is_latest = seen.insert(path.clone());
+                VersionedEntry {
+                    version: entry.metadata().version().map(str::to_string),
+                    path,
+                    is_latest,
+                }
+            })
+            .collect())
+    }
+}
+
+#[gen_stub_pyclass]
+#[pyclass(module = "opendal", name = "AsyncOperator")]
+pub struct PyAsyncOperator {
+    op: Option<ocore::Operator>,
+    map: HashMap<String, String>,
+}
+
+impl PyAsyncOperator {
+    /// Async counterpart of [`PyOperator::new_empty`].
+    pub fn new_empty() -> Self {
+        Self {
+            op: None,
+            map: HashMap::new(),
+        }
+    }
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl PyAsyncOperator {
+    /// Async counterpart of [`PyOperator::_from_capsule`].
+    #[classmethod]
+    #[pyo3(signature = (capsule, map))]
+    fn _from_capsule(
+        _cls: &Bound<'_, PyType>,
+        capsule: Bound<'_, PyCapsule>,
+        map: HashMap<String, String>,
+    ) -> PyResult<Self> {
+        let op = from_operator_capsule(&capsule)?;
+        Ok(Self {
+            op: Some(op),
+            map,
+        })
+    }
+
+    /// Async counterpart of [`PyOperator::read_version`].
+    #[pyo3(signature = (path, version=None))]
+    fn read_version<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+        version: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let op = self.op.clone().ok_or_else(unbound_operator_err)?;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut req = op.read_with(&path);
+            if let Some(v) = &version {
+                req = req.version(v);
+            }
+            let buf = req
+                .await
+                .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+            Ok(buf.to_vec())
+        })
+    }
+
+    /// Async counterpart of [`PyOperator::stat_version`].
+    #[pyo3(signature = (path, version=None))]
+    fn stat_version<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+        version: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let op = self.op.clone().ok_or_else(unbound_operator_err)?;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut req = op.stat_with(&path);
+            if let Some(v) = &version {
+                req = req.version(v);
+            }
+            let meta = req
+                .await
+                .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+
+            Python::with_gil(|py| -> PyResult<Py<PyAny>> {
+                let dict = pyo3::types::PyDict::new(py);
+                dict.set_item("content_length", meta.content_length())?;
+                dict.set_item("is_dir", meta.is_dir())?;
+                dict.set_item("mode", format!("{:?}", meta.mode()))?;
+                dict.set_item("version", meta.version())?;
+                Ok(dict.into())
+            })
+        })
+    }
+
+    /// Async counterpart of [`PyOperator::delete_version`].
+    #[pyo3(signature = (path, version=None))]
+    fn delete_version<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+        version: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let op = self.op.clone().ok_or_else(unbound_operator_err)?;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut req = op.delete_with(&path);
+            if let Some(v) = &version {
+                req = req.version(v);
+            }
+            req.await
+                .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))
+        })
+    }
+
+    /// Async counterpart of [`PyOperator::list_with_versions`].
+    fn list_with_versions<'py>(
+        &self,
+        py: Python<'py>,
+        path: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let op = self.op.clone().ok_or_else(unbound_operator_err)?;
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let entries = op
+                .list_with(&path)
+                .versions(true)
+                .await
+                .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(err.to_string()))?;
+
+            let mut seen = std::collections::HashSet::new();
+            Ok(entries
+                .into_iter()
+                .map(|entry| {
+                    let path = entry.path().to_string();
+                    let is_latest = seen.insert(path.clone());
+                    VersionedEntry {
+                        version: entry.metadata().version().map(str::to_string),
+                        path,
+                        is_latest,
+                    }
+                })
+                .collect::<Vec<_>>())
+        })
+    }
+}
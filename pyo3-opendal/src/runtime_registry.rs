@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Process-wide shared Tokio runtime registry.
+//!
+//! Every service extension module (`opendal-service-s3`, `opendal-service-fs`,
+//! ...) is its own independently compiled Python extension, so `pyo3-opendal`
+//! is statically linked into each one separately -- a plain `OnceLock` here
+//! would give every service its own, unshared copy. To actually collapse
+//! loaded services onto a single Tokio thread pool, the first service to
+//! initialize one publishes its `tokio::runtime::Handle` as a capsule
+//! attribute on the `opendal` module, the same way [`crate::export`] hands
+//! operators back to the single, shared `opendal.Operator` class: module
+//! attribute lookup goes through `sys.modules`, which is genuinely shared
+//! process state, unlike a Rust static duplicated per binary.
+
+use std::ffi::{CStr, CString};
+use std::sync::OnceLock;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyCapsuleMethods};
+
+const SHARED_RUNTIME_HANDLE_ATTR: &str = "_shared_runtime_handle";
+const SHARED_RUNTIME_HANDLE_CAPSULE_NAME: &CStr = c"opendal_runtime_handle";
+
+/// Per-binary cache so repeated calls from the *same* compiled extension
+/// don't re-import `opendal` and re-check its attributes every time.
+static LOCAL_HANDLE: OnceLock<tokio::runtime::Handle> = OnceLock::new();
+
+/// Returns the process-wide shared Tokio runtime handle, publishing one if
+/// none has been registered yet.
+///
+/// The first caller (from whichever service loads first) wins: it creates a
+/// runtime via [`pyo3_async_runtimes::tokio::get_runtime`] and publishes its
+/// handle on the `opendal` module. Every subsequently loaded service -- each
+/// running in its own, separately linked binary -- finds that handle instead
+/// of spinning up another thread pool of its own.
+pub fn shared_runtime_handle(py: Python) -> PyResult<tokio::runtime::Handle> {
+    if let Some(handle) = LOCAL_HANDLE.get() {
+        return Ok(handle.clone());
+    }
+
+    let opendal_mod = py.import("opendal")?;
+    let handle = match opendal_mod.getattr(SHARED_RUNTIME_HANDLE_ATTR) {
+        Ok(attr) => {
+            let capsule = attr.downcast::<PyCapsule>()?;
+            from_handle_capsule(capsule)?
+        }
+        Err(_) => {
+            let handle = pyo3_async_runtimes::tokio::get_runtime().handle().clone();
+            let capsule = to_handle_capsule(py, handle.clone())?;
+            opendal_mod.setattr(SHARED_RUNTIME_HANDLE_ATTR, capsule)?;
+            handle
+        }
+    };
+
+    // Another thread may have raced us and already set the local cache;
+    // that's fine, both handles point at the same shared runtime.
+    let _ = LOCAL_HANDLE.set(handle.clone());
+    Ok(handle)
+}
+
+fn to_handle_capsule(py: Python, handle: tokio::runtime::Handle) -> PyResult<Bound<PyCapsule>> {
+    PyCapsule::new(
+        py,
+        handle,
+        Some(CString::from(SHARED_RUNTIME_HANDLE_CAPSULE_NAME)),
+    )
+}
+
+fn from_handle_capsule(capsule: &Bound<PyCapsule>) -> PyResult<tokio::runtime::Handle> {
+    let ptr = capsule
+        .pointer_checked(Some(SHARED_RUNTIME_HANDLE_CAPSULE_NAME))?
+        .cast::<tokio::runtime::Handle>();
+    Ok(unsafe { ptr.as_ref() }.clone())
+}
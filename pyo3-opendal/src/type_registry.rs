@@ -22,7 +22,9 @@
 //! type instances.
 
 use pyo3::prelude::*;
-use std::sync::OnceLock;
+use pyo3_stub_gen::derive::*;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
 use super::{PyAsyncOperator, PyOperator};
 
@@ -73,3 +75,63 @@ pub fn get_shared_py_async_operator() -> &'static Py<PyAny> {
 pub fn is_initialized() -> bool {
     SHARED_REGISTRY.get().is_some()
 }
+
+/// A third-party service registered through [`register_service`]: just the
+/// Python callable that builds its operators, keyed by service name.
+struct ServiceRegistration {
+    config_builder: Py<PyAny>,
+}
+
+static SERVICE_REGISTRY: OnceLock<Mutex<HashMap<String, ServiceRegistration>>> = OnceLock::new();
+
+fn service_registry() -> &'static Mutex<HashMap<String, ServiceRegistration>> {
+    SERVICE_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registers an out-of-tree, independently-compiled service so it can
+/// contribute operators through the same [`SharedTypeRegistry`] base types
+/// every built-in service (e.g. `S3Service`) uses, rather than requiring the
+/// service to be compiled into this crate.
+///
+/// `config_builder` is the service's config class itself (e.g. an `S3Service`-
+/// shaped `*Service` class), expected to expose `to_operator`/
+/// `to_async_operator` methods whose returned capsules are handed to
+/// `opendal.Operator._from_capsule`/`opendal.AsyncOperator._from_capsule`.
+/// Those capsules are only accepted if they carry the ABI version this
+/// build's [`crate::ffi`] module understands (see
+/// [`crate::ffi::OPERATOR_CAPSULE_ABI_VERSION`]) -- registering a service
+/// does not relax that check, it only makes the service discoverable by
+/// name for callers that want to look it up dynamically instead of
+/// importing it directly.
+#[gen_stub_pyfunction]
+#[pyfunction]
+pub fn register_service(name: String, config_builder: Py<PyAny>) -> PyResult<()> {
+    let mut registry = service_registry().lock().map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("service registry poisoned")
+    })?;
+
+    if registry.contains_key(&name) {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "service {name:?} is already registered"
+        )));
+    }
+
+    registry.insert(name, ServiceRegistration { config_builder });
+    Ok(())
+}
+
+/// Looks up a previously [`register_service`]d service's config builder by name.
+pub fn get_registered_service(name: &str) -> Option<Py<PyAny>> {
+    service_registry()
+        .lock()
+        .ok()
+        .and_then(|registry| registry.get(name).map(|r| r.config_builder.clone()))
+}
+
+/// Names of every service registered so far, for introspection/diagnostics.
+pub fn registered_service_names() -> Vec<String> {
+    service_registry()
+        .lock()
+        .map(|registry| registry.keys().cloned().collect())
+        .unwrap_or_default()
+}
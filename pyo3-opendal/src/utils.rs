@@ -24,10 +24,19 @@ use crate::ocore::Configurator;
 use pyo3::IntoPyObjectExt;
 use pyo3::ffi;
 use pyo3::prelude::*;
+use pyo3::types::PyDelta;
 use serde_json::Value;
 
 /// A bytes-like object that implements buffer protocol.
-#[pyclass(module = "opendal")]
+///
+/// `inner` is set once at construction and never mutated afterwards, and the
+/// class is `frozen` so PyO3 never needs an exclusive (mutable) borrow to
+/// read it. That makes concurrent `__getbuffer__` calls from multiple
+/// threads on a free-threaded (no-GIL, PEP 703) interpreter safe: readers
+/// only ever take shared borrows of `self`, and `PyBuffer_FillInfo`'s `obj`
+/// parameter pins `self` (CPython incref's it, and decrefs it again on
+/// `PyBuffer_Release`) so a view can never outlive its backing `Buffer`.
+#[pyclass(module = "opendal", frozen)]
 pub struct Buffer {
     inner: Vec<u8>,
 }
@@ -57,7 +66,7 @@ impl Buffer {
 #[pymethods]
 impl Buffer {
     unsafe fn __getbuffer__(
-        slf: PyRefMut<Self>,
+        slf: PyRef<Self>,
         view: *mut ffi::Py_buffer,
         flags: c_int,
     ) -> PyResult<()> {
@@ -153,6 +162,7 @@ macro_rules! define_build_operator {
         #[pyfunction]
         #[pyo3(signature = (scheme, is_async, **kwargs))]
         pub fn __build_operator__(
+            py: Python,
             scheme: String,
             is_async: bool,
             kwargs: Option<&Bound<PyDict>>,
@@ -171,8 +181,7 @@ macro_rules! define_build_operator {
                 .into_operator_uri()
                 .map_err(pyo3_opendal::format_pyerr)?;
 
-            let runtime = pyo3_async_runtimes::tokio::get_runtime();
-            let handle = runtime.handle().clone();
+            let handle = pyo3_opendal::shared_runtime_handle(py)?;
 
             let op = Operator::from_uri(uri.clone())
                 .map_err(pyo3_opendal::format_pyerr)?
@@ -239,3 +248,127 @@ impl<T: serde::Serialize> ToStringMap for T {
         Ok(map)
     }
 }
+
+/// A config value accepted from Python as a `datetime.timedelta`, a bare
+/// number of seconds, or an already-formatted duration string, and rendered
+/// via `Display`/`ToString` as the duration string OpenDAL services expect
+/// (e.g. `"10s"`, `"1500ms"`, `"2m"`).
+///
+/// Generated service factory code inserts config values into a
+/// `HashMap<String, String>` by calling `.to_string()` on them, so this type
+/// exists purely to give a `Duration`-shaped config field a `ToString` that
+/// produces what services actually parse -- `std::time::Duration` itself has
+/// no such conversion.
+pub enum ConfigDuration {
+    /// A `datetime.timedelta` or bare number of seconds, formatted on demand.
+    Exact(std::time::Duration),
+    /// A string passed straight through, for callers already passing the
+    /// service's native duration syntax (e.g. `"10s"`).
+    Raw(String),
+}
+
+impl<'py> FromPyObject<'py> for ConfigDuration {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(delta) = ob.downcast::<PyDelta>() {
+            let total_micros = (delta.get_days() as i64 * 86_400 + delta.get_seconds() as i64)
+                * 1_000_000
+                + delta.get_microseconds() as i64;
+            return Ok(Self::Exact(std::time::Duration::from_micros(
+                total_micros.max(0) as u64,
+            )));
+        }
+        if let Ok(secs) = ob.extract::<f64>() {
+            return Ok(Self::Exact(std::time::Duration::from_secs_f64(secs)));
+        }
+        if let Ok(s) = ob.extract::<String>() {
+            return Ok(Self::Raw(s));
+        }
+
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "expected a datetime.timedelta, a number of seconds, or a duration string",
+        ))
+    }
+}
+
+impl std::fmt::Display for ConfigDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Raw(s) => write!(f, "{s}"),
+            Self::Exact(d) => write!(f, "{}", format_duration(*d)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Buffer;
+    use pyo3::Python;
+
+    /// Reads `buf` back through Python's builtin `bytes()`, which goes
+    /// through the buffer protocol (`Buffer::__getbuffer__`) rather than any
+    /// Rust-side shortcut.
+    fn read_via_buffer_protocol(py: Python<'_>, buf: &pyo3::Py<Buffer>) -> Vec<u8> {
+        py.eval(c"bytes", None, None)
+            .unwrap()
+            .call1((buf,))
+            .unwrap()
+            .extract()
+            .unwrap()
+    }
+
+    /// Many threads holding shared (read-only) references to the same
+    /// `Buffer` and repeatedly reading it through `__getbuffer__` must never
+    /// race or corrupt the data `Buffer` is `frozen` specifically to make
+    /// safe -- this is the scenario free-threaded (no-GIL, PEP 703)
+    /// interpreters allow without any GIL serializing access between
+    /// threads. This test runs under a normal GIL-enabled interpreter (the
+    /// only kind available in this environment) and so each thread still
+    /// acquires the GIL before reading; it exercises `Buffer`'s thread
+    /// safety under concurrent access but does not itself prove behavior
+    /// under a `Py_GIL_DISABLED` build.
+    #[test]
+    fn buffer_read_from_multiple_threads() {
+        let expected = vec![1u8, 2, 3, 4, 5, 6, 7, 8];
+        let buf: pyo3::Py<Buffer> =
+            Python::with_gil(|py| pyo3::Py::new(py, Buffer::new(expected.clone())).unwrap());
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let buf = Python::with_gil(|py| buf.clone_ref(py));
+                let expected = expected.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        let got = Python::with_gil(|py| read_via_buffer_protocol(py, &buf));
+                        assert_eq!(got, expected);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+}
+
+/// Formats a `Duration` as the largest unit that represents it exactly,
+/// falling back to microseconds when it doesn't divide evenly.
+fn format_duration(d: std::time::Duration) -> String {
+    let micros = d.as_micros();
+    if micros == 0 {
+        return "0ms".to_string();
+    }
+    if micros % 1_000_000 == 0 {
+        let secs = micros / 1_000_000;
+        return if secs % 60 == 0 {
+            format!("{}m", secs / 60)
+        } else {
+            format!("{secs}s")
+        };
+    }
+    if micros % 1_000 == 0 {
+        format!("{}ms", micros / 1_000)
+    } else {
+        format!("{micros}us")
+    }
+}
@@ -31,14 +31,14 @@ use std::collections::HashMap;
 /// Factory function to create a new S3 blocking operator
 #[pyfunction]
 #[pyo3(signature = (**kwargs))]
-pub fn create_s3_operator(kwargs: Option<&Bound<PyDict>>) -> PyResult<OpendalOperator> {
+pub fn create_s3_operator(py: Python, kwargs: Option<&Bound<PyDict>>) -> PyResult<OpendalOperator> {
     let mut map = HashMap::new();
     if let Some(kwargs) = kwargs {
         map = kwargs.extract::<HashMap<String, String>>()?;
     }
 
     let runtime = pyo3_async_runtimes::tokio::get_runtime();
-    let handle = runtime.handle().clone();
+    let handle = pyo3_opendal::shared_runtime_handle(py)?;
 
     let op = Operator::via_iter(S3_SCHEME, map)
         .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("build error: {err}")))?
@@ -55,14 +55,16 @@ pub fn create_s3_operator(kwargs: Option<&Bound<PyDict>>) -> PyResult<OpendalOpe
 /// Factory function to create a new S3 async operator
 #[pyfunction]
 #[pyo3(signature = (**kwargs))]
-pub fn create_s3_async_operator(kwargs: Option<&Bound<PyDict>>) -> PyResult<OpendalAsyncOperator> {
+pub fn create_s3_async_operator(
+    py: Python,
+    kwargs: Option<&Bound<PyDict>>,
+) -> PyResult<OpendalAsyncOperator> {
     let mut map = HashMap::new();
     if let Some(kwargs) = kwargs {
         map = kwargs.extract::<HashMap<String, String>>()?;
     }
 
-    let runtime = pyo3_async_runtimes::tokio::get_runtime();
-    let handle = runtime.handle().clone();
+    let handle = pyo3_opendal::shared_runtime_handle(py)?;
 
     let op = Operator::via_iter(S3_SCHEME, map)
         .map_err(|err| pyo3::exceptions::PyValueError::new_err(format!("build error: {err}")))?